@@ -1,4 +1,7 @@
+use std::fmt::Display;
+
 use anyhow::Result;
+use aoc_common::{parse, solution::Solution};
 use itertools::Itertools;
 
 // NOTE: More lengthy implementation(though these are MUCH FASTERRRR)
@@ -36,24 +39,35 @@ use itertools::Itertools;
 // }
 
 // NOTE: A much cleaner implementation(though this is MUCH SLOWERRR)
-fn solve(nums: Vec<u32>, n: usize) -> u32 {
-    for comb in nums.into_iter().combinations(n) {
-        if comb.iter().sum::<u32>() == 2020 {
-            return comb.iter().product();
+fn solve(nums: &[u32], n: usize) -> u32 {
+    for comb in nums.iter().combinations(n) {
+        if comb.iter().copied().sum::<u32>() == 2020 {
+            return comb.iter().copied().product();
         }
     }
 
     0
 }
 
-fn main() -> Result<()> {
-    let nums: Vec<u32> = std::fs::read_to_string("./data/inputs/1.txt")?
-        .lines()
-        .map(|line| line.parse().expect("line is not a number"))
-        .collect();
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input)
+    }
+
+    fn part1(nums: &Self::Input) -> Result<impl Display> {
+        Ok(solve(nums, 2))
+    }
 
-    println!("Part 1: {}", solve(nums.clone(), 2));
-    println!("Part 2: {}", solve(nums, 3));
+    fn part2(nums: &Self::Input) -> Result<impl Display> {
+        Ok(solve(nums, 3))
+    }
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 1, sample: [514579, 241861950], real: [1006176, 199132160]);
 }
@@ -0,0 +1,5 @@
+use anyhow::Result;
+
+fn main() -> Result<()> {
+    aoc_common::solution::main_for_day(&aoc2020::YEAR, 2)
+}
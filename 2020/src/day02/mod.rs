@@ -0,0 +1,94 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{ensure, Result};
+use aoc_common::{parse, scan, solution::Solution};
+
+#[derive(Debug)]
+struct PasswordPolicy {
+    min: usize,
+    max: usize,
+    letter: char,
+}
+
+impl PasswordPolicy {
+    fn new(min: usize, max: usize, letter: char) -> PasswordPolicy {
+        PasswordPolicy { min, max, letter }
+    }
+}
+
+#[derive(Debug)]
+pub struct Password {
+    val: String,
+    policy: PasswordPolicy,
+}
+
+impl Password {
+    fn new(val: String, policy: PasswordPolicy) -> Password {
+        Password { val, policy }
+    }
+
+    fn is_valid_1(&self) -> bool {
+        let count = self.val.matches(&String::from(self.policy.letter)).count();
+
+        if count < self.policy.min || count > self.policy.max {
+            return false;
+        }
+
+        true
+    }
+
+    fn is_valid_2(&self) -> bool {
+        // the positions start at 1, and a position past the end of the password has no letter
+        let is_letter_at = |pos: usize| self.val.chars().nth(pos - 1) == Some(self.policy.letter);
+        let pr_at_pos_1 = is_letter_at(self.policy.min);
+        let pr_at_pos_2 = is_letter_at(self.policy.max);
+
+        if pr_at_pos_1 || pr_at_pos_2 {
+            if pr_at_pos_1 && pr_at_pos_2 {
+                return false;
+            }
+
+            return true;
+        }
+
+        false
+    }
+}
+
+impl FromStr for Password {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (min, max, letter, val) = scan!(s, "{}-{} {}: {}" => usize, usize, char, String)?;
+        ensure!(
+            0 < min && min <= max,
+            "{}-{} is not a range of positions starting at 1",
+            min,
+            max
+        );
+        Ok(Password::new(val, PasswordPolicy::new(min, max, letter)))
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Password>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input)
+    }
+
+    fn part1(passwords: &Self::Input) -> Result<impl Display> {
+        Ok(passwords.iter().filter(|pass| pass.is_valid_1()).count())
+    }
+
+    fn part2(passwords: &Self::Input) -> Result<impl Display> {
+        Ok(passwords.iter().filter(|pass| pass.is_valid_2()).count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 2, sample: [2, 1], real: [538, 489]);
+}
@@ -0,0 +1,55 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::solution::Solution;
+
+fn get_num_trees(forest: &[Vec<char>], slope: (usize, usize)) -> u32 {
+    let forest_width = forest[0].len();
+
+    let mut pos: (usize, usize) = (0, 0);
+    let mut trees = 0;
+    while pos.1 < forest.len() {
+        // make sure the pos does not go out of the forest
+        if pos.0 > (forest_width - 1) {
+            pos.0 = (pos.0 % (forest_width - 1)) - 1;
+        }
+
+        let curr = &forest[pos.1][pos.0];
+        if *curr == '#' {
+            trees += 1;
+        }
+
+        // move to new pos
+        pos.0 += slope.0;
+        pos.1 += slope.1;
+    }
+
+    trees
+}
+
+const SLOPES: [(usize, usize); 5] = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(|line| line.chars().collect()).collect())
+    }
+
+    fn part1(forest: &Self::Input) -> Result<impl Display> {
+        Ok(get_num_trees(forest, SLOPES[1]))
+    }
+
+    fn part2(forest: &Self::Input) -> Result<impl Display> {
+        Ok(SLOPES
+            .into_iter()
+            .fold(1, |acc, slope| acc * get_num_trees(forest, slope)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 3, sample: [7, 336], real: [181, 1260601650]);
+}
@@ -0,0 +1,167 @@
+use std::{fmt::Display, ops::RangeInclusive, str::FromStr};
+
+use anyhow::{anyhow, bail, Context, Error, Result};
+use aoc_common::{parse, solution::Solution};
+
+fn not_in_range<T>(val: Option<T>, min: T, max: T) -> bool
+where
+    T: PartialOrd,
+{
+    val < Some(min) || val > Some(max)
+}
+
+pub struct Passport {
+    // Birth Year
+    byr: Option<u16>,
+    // Issue Year
+    iyr: Option<u16>,
+    // Expiration Year
+    eyr: Option<u16>,
+    // Height
+    hgt: Option<String>,
+    // Hair Color
+    hcl: Option<String>,
+    // Eye Color
+    ecl: Option<String>,
+    // Passport ID (note: This is a string as it may contain leading zeroes which we need to preserve)
+    pid: Option<String>,
+    // Country ID
+    cid: Option<u16>,
+}
+
+impl Passport {
+    fn new() -> Passport {
+        Passport {
+            byr: None,
+            iyr: None,
+            eyr: None,
+            hgt: None,
+            hcl: None,
+            ecl: None,
+            pid: None,
+            cid: None,
+        }
+    }
+
+    fn is_valid_1(&self) -> bool {
+        !(self.byr.is_none()
+            || self.iyr.is_none()
+            || self.eyr.is_none()
+            || self.hgt.is_none()
+            || self.hcl.is_none()
+            || self.ecl.is_none()
+            || self.pid.is_none())
+    }
+
+    fn is_valid_2(&self) -> bool {
+        if !self.is_valid_1() {
+            return false;
+        }
+
+        let (Some(hgt), Some(hcl), Some(ecl), Some(pid)) =
+            (&self.hgt, &self.hcl, &self.ecl, &self.pid)
+        else {
+            return false;
+        };
+
+        if not_in_range(self.byr, 1920, 2002)
+            || not_in_range(self.iyr, 2010, 2020)
+            || not_in_range(self.eyr, 2020, 2030)
+            || pid.len() != 9
+        {
+            return false;
+        }
+
+        let height_in = |hgt: &str, range: RangeInclusive<u8>| {
+            hgt.parse().is_ok_and(|hgt| range.contains(&hgt))
+        };
+        let valid_hgt = if let Some(hgt) = hgt.strip_suffix("cm") {
+            height_in(hgt, 150..=193)
+        } else if let Some(hgt) = hgt.strip_suffix("in") {
+            height_in(hgt, 59..=76)
+        } else {
+            false
+        };
+        if !valid_hgt {
+            return false;
+        };
+
+        if !(hcl.len() == 7 || hcl.starts_with('#')) {
+            return false;
+        }
+        let Some(hcl) = hcl.get(1..) else {
+            return false;
+        };
+        let accepted = "0123456789abcdef";
+        for c in hcl.chars() {
+            if !accepted.contains(c) {
+                return false;
+            }
+        }
+
+        let accepted = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+        if !accepted.contains(&ecl.as_str()) {
+            return false;
+        }
+
+        true
+    }
+}
+
+impl FromStr for Passport {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut pass = Passport::new();
+
+        let props = s.lines().flat_map(|line| line.split(' '));
+
+        for prop in props {
+            let (key, val): (&str, &str) = prop
+                .split_once(":")
+                .ok_or_else(|| anyhow!("expected a key and a value in {:?}", prop))?;
+            let number = || {
+                val.parse::<u16>()
+                    .with_context(|| format!("invalid {} {:?}", key, val))
+            };
+
+            match key {
+                "byr" => pass.byr = Some(number()?),
+                "iyr" => pass.iyr = Some(number()?),
+                "eyr" => pass.eyr = Some(number()?),
+                "hgt" => pass.hgt = Some(val.to_string()),
+                "hcl" => pass.hcl = Some(val.to_string()),
+                "ecl" => pass.ecl = Some(val.to_string()),
+                "pid" => pass.pid = Some(val.to_string()),
+                "cid" => pass.cid = Some(number()?),
+
+                _ => bail!("unknown property {:?}", key),
+            }
+        }
+
+        Ok(pass)
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Passport>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::sections(input).map(|block| block.parse()).collect()
+    }
+
+    fn part1(passports: &Self::Input) -> Result<impl Display> {
+        Ok(passports.iter().filter(|pass| pass.is_valid_1()).count())
+    }
+
+    fn part2(passports: &Self::Input) -> Result<impl Display> {
+        Ok(passports.iter().filter(|pass| pass.is_valid_2()).count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 4, sample: [2, 2], real: [192, 101]);
+}
@@ -0,0 +1,112 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, bail, Error, Result};
+use aoc_common::{error::SolveError, parse, solution::Solution};
+use itertools::Itertools;
+
+const TOTAL_ROWS: usize = 128;
+const TOTAL_COLS: usize = 8;
+
+struct BoardingPass {
+    row_specifier: String,
+    col_specifier: String,
+}
+
+impl BoardingPass {
+    fn new(row_specifier: String, col_specifier: String) -> BoardingPass {
+        BoardingPass {
+            row_specifier,
+            col_specifier,
+        }
+    }
+
+    fn get_row(&self) -> usize {
+        let mut range: (usize, usize) = (0, TOTAL_ROWS - 1);
+
+        // the letters were checked when parsing the pass, so they narrow the range to one row
+        for letter in self.row_specifier.chars() {
+            let half: usize = (range.0 + range.1).div_ceil(2) - 1;
+            if letter == 'F' {
+                range = (range.0, half);
+            } else {
+                range = (half + 1, range.1);
+            }
+        }
+
+        range.0
+    }
+
+    fn get_col(&self) -> usize {
+        let mut range: (usize, usize) = (0, TOTAL_COLS - 1);
+
+        // the letters were checked when parsing the pass, so they narrow the range to one column
+        for letter in self.col_specifier.chars() {
+            let half: usize = (range.0 + range.1).div_ceil(2) - 1;
+            if letter == 'L' {
+                range = (range.0, half);
+            } else {
+                range = (half + 1, range.1);
+            }
+        }
+
+        range.0
+    }
+
+    fn get_seat_id(&self) -> usize {
+        (self.get_row() * 8) + self.get_col()
+    }
+}
+
+impl FromStr for BoardingPass {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let valid = s.len() == 10
+            && s.char_indices().all(|(i, c)| match i {
+                0..7 => c == 'F' || c == 'B',
+                _ => c == 'L' || c == 'R',
+            });
+        if !valid {
+            bail!("{:?} is not 7 of F or B followed by 3 of L or R", s);
+        }
+
+        Ok(BoardingPass::new(s[..7].to_string(), s[7..].to_string()))
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    /// The seat ids of every boarding pass, sorted.
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let passes: Vec<BoardingPass> = parse::lines(input)?;
+        Ok(passes.iter().map(|p| p.get_seat_id()).sorted().collect())
+    }
+
+    fn part1(seat_ids: &Self::Input) -> Result<impl Display> {
+        seat_ids
+            .last()
+            .ok_or_else(|| anyhow!("there are no boarding passes"))
+    }
+
+    fn part2(seat_ids: &Self::Input) -> Result<impl Display> {
+        for (i, seat_id) in seat_ids.iter().enumerate() {
+            if i + 1 == seat_ids.len() {
+                break;
+            }
+
+            if seat_id + 1 != seat_ids[i + 1] {
+                return Ok(seat_id + 1);
+            }
+        }
+
+        Err(SolveError::no_solution("there is no empty seat between the boarding passes").into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 5, sample: [820, 120], real: [828, 565]);
+}
@@ -0,0 +1,51 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{parse, solution::Solution};
+use itertools::Itertools;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse::sections(input).map(String::from).collect())
+    }
+
+    fn part1(groups: &Self::Input) -> Result<impl Display> {
+        Ok(groups
+            .iter()
+            .map(|group| {
+                group
+                    .split('\n')
+                    .flat_map(|person| person.chars())
+                    .unique()
+                    .count()
+            })
+            .sum::<usize>())
+    }
+
+    fn part2(groups: &Self::Input) -> Result<impl Display> {
+        Ok(groups
+            .iter()
+            .map(|group| {
+                let persons: Vec<&str> = group.split('\n').collect();
+
+                let len = persons.len();
+                persons
+                    .iter()
+                    .flat_map(|person| person.chars())
+                    .counts()
+                    .into_iter()
+                    .filter(|val| val.1 == len)
+                    .count()
+            })
+            .sum::<usize>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 6, sample: [11, 6], real: [6170, 2947]);
+}
@@ -0,0 +1,114 @@
+use std::{collections::HashMap, fmt::Display};
+
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    combinator::{self, lines, literal, opt, separated, uint, word, Parser},
+    search::reachable,
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Bag {
+    name: String,
+    quantity: u16,
+}
+
+impl Bag {
+    fn new(name: String, quantity: u16) -> Bag {
+        Bag { name, quantity }
+    }
+}
+
+fn get_num_bags_contained(bags: &HashMap<String, Vec<Bag>>, contains: &[Bag]) -> u16 {
+    let mut total: u16 = 0;
+    for bag in contains.iter() {
+        let bags_inside = get_bags(bags, bag);
+        total += match bags_inside {
+            None => bag.quantity,
+            Some(bags_inside) => bag.quantity * (get_num_bags_contained(bags, bags_inside) + 1),
+        };
+    }
+    total
+}
+
+/// The number of kinds of bags that can eventually hold a shiny gold bag.
+fn num_bags_containing_shiny_gold(bags: &HashMap<String, Vec<Bag>>) -> usize {
+    let mut contained_in: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (name, contains) in bags.iter() {
+        for bag in contains.iter() {
+            contained_in.entry(&bag.name).or_default().push(name);
+        }
+    }
+
+    // The shiny gold bag itself is reachable too, but it does not count
+    reachable("shiny gold", |bag| {
+        contained_in.get(bag).cloned().unwrap_or_default()
+    })
+    .len()
+        - 1
+}
+
+fn get_bags<'a>(bags: &'a HashMap<String, Vec<Bag>>, bag: &Bag) -> Option<&'a Vec<Bag>> {
+    for (b, contains) in bags.iter() {
+        if bag.name == *b && !contains.is_empty() {
+            return Some(contains);
+        }
+    }
+
+    None
+}
+
+/// The name of a kind of bag, which is an adjective followed by a color such as `shiny gold`.
+fn name<'a>() -> impl Parser<'a, String> {
+    (word(), literal(" "), word()).map(|(adjective, _, color)| format!("{} {}", adjective, color))
+}
+
+/// `<name> bags contain ` followed by `no other bags` or the bags inside such as `1 bright white
+/// bag, 2 muted yellow bags`, and a `.`.
+fn rule<'a>() -> impl Parser<'a, (String, Vec<Bag>)> {
+    let bag = || {
+        (
+            uint(),
+            literal(" "),
+            name(),
+            literal(" bag"),
+            opt(literal("s")),
+        )
+            .map(|(quantity, _, name, _, _)| Bag::new(name, quantity))
+    };
+    let contains = move |input: &'a str| match input.strip_prefix("no other bags") {
+        Some(rest) => Ok((vec![], rest)),
+        None => separated(bag(), literal(", ")).parse_from(input),
+    };
+
+    (name(), literal(" bags contain "), contains, literal("."))
+        .map(|(name, _, contains, _)| (name, contains))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    /// The bags directly inside each kind of bag.
+    type Input = HashMap<String, Vec<Bag>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let rules = combinator::parse(input, lines(rule()))?;
+        Ok(rules.into_iter().collect())
+    }
+
+    fn part1(bags: &Self::Input) -> Result<impl Display> {
+        Ok(num_bags_containing_shiny_gold(bags))
+    }
+
+    fn part2(bags: &Self::Input) -> Result<impl Display> {
+        let shiny_gold = bags
+            .get("shiny gold")
+            .ok_or_else(|| anyhow!("there is no rule for shiny gold bags"))?;
+        Ok(get_num_bags_contained(bags, shiny_gold))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 7, sample: [4, 32], real: [213, 38426]);
+}
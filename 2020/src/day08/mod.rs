@@ -0,0 +1,130 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, bail, Result};
+use aoc_common::{
+    combinator::{self, int, lines, literal, word, Parser},
+    error::SolveError,
+    solution::Solution,
+};
+
+#[derive(Debug, Clone)]
+enum Instruction {
+    Acc(i32),
+    Jmp(i32),
+    Nop(i32),
+}
+
+#[derive(Debug, Clone)]
+pub struct Command {
+    instruction: Instruction,
+}
+
+impl Command {
+    fn new(instruction: &str, arg: i32) -> Result<Command> {
+        let inst = match instruction {
+            "acc" => Instruction::Acc(arg),
+            "jmp" => Instruction::Jmp(arg),
+            "nop" => Instruction::Nop(0),
+            _ => bail!("unknown instruction {:?}", instruction),
+        };
+
+        Ok(Command { instruction: inst })
+    }
+
+    fn is_jmp(&self) -> bool {
+        matches!(self.instruction, Instruction::Jmp(_))
+    }
+
+    /// Turns a jmp into a nop and the other way around, leaving an acc as it is.
+    fn swap_jmp_and_nop(&mut self) {
+        self.instruction = match self.instruction {
+            Instruction::Jmp(val) => Instruction::Nop(val),
+            Instruction::Nop(val) => Instruction::Jmp(val),
+            Instruction::Acc(val) => Instruction::Acc(val),
+        };
+    }
+}
+
+fn command<'a>() -> impl Parser<'a, Command> {
+    (word(), literal(" "), int()).try_map(|(instruction, _, arg)| Command::new(instruction, arg))
+}
+
+/// The accumulator once the program runs past its last command, or `None` if it loops forever
+/// instead. With `brk` set, a loop stops the program right before a command would run twice.
+fn get_acc_if_infi(cmds: &[Command], brk: bool) -> Result<Option<i32>> {
+    let mut run: Vec<usize> = vec![];
+    let mut acc: i32 = 0;
+    let mut i: usize = 0;
+    while let Some(cmd) = cmds.get(i) {
+        if run.contains(&i) {
+            if brk {
+                break;
+            } else {
+                return Ok(None);
+            }
+        }
+        run.push(i);
+
+        i = match cmd.instruction {
+            Instruction::Acc(val) => {
+                acc += val;
+                i + 1
+            }
+            Instruction::Jmp(val) => i.checked_add_signed(val as isize).ok_or_else(|| {
+                SolveError::no_solution(format!(
+                    "command {} jumps to before the start of the program",
+                    i + 1
+                ))
+            })?,
+            Instruction::Nop(_) => i + 1,
+        };
+    }
+
+    Ok(Some(acc))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        combinator::parse(input, lines(command()))
+    }
+
+    fn part1(cmds: &Self::Input) -> Result<impl Display> {
+        get_acc_if_infi(cmds, true)?.ok_or_else(|| anyhow!("the program did not run"))
+    }
+
+    fn part2(cmds: &Self::Input) -> Result<impl Display> {
+        let mut cmds = cmds.clone();
+
+        // NOTE: I got the answer here directly! If we would not get the answer
+        // here, we would have to do this same for nop -> jmp
+        let jmp_ids: Vec<usize> = cmds
+            .iter()
+            .enumerate()
+            .filter(|(_, cmd)| cmd.is_jmp())
+            .map(|(i, _)| i)
+            .collect();
+        jmp_ids
+            .iter()
+            .find_map(|id| {
+                // change the val for now
+                cmds[*id].swap_jmp_and_nop();
+                // a change that jumps to before the start does not fix the program either
+                let ans = get_acc_if_infi(&cmds, false).unwrap_or(None);
+                // revert it back
+                cmds[*id].swap_jmp_and_nop();
+                ans
+            })
+            .ok_or_else(|| {
+                SolveError::no_solution("changing a single jmp never stops the loop").into()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 8, sample: [5, 8], real: [1749, 515]);
+}
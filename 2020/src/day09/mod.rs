@@ -0,0 +1,93 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{error::SolveError, parse, solution::Solution};
+use itertools::Itertools;
+
+const PREAMBLE: usize = 25;
+const SAMPLE_PREAMBLE: usize = 5;
+
+fn comb_is_valid(allowed_nums: &[u64], curr: &u64) -> bool {
+    for comb in allowed_nums.iter().combinations(2) {
+        if (comb[0] + comb[1]) == *curr {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// The sample is too short for a preamble of 25 numbers and uses one of 5 instead.
+fn preamble(nums: &[u64]) -> usize {
+    if nums.len() > PREAMBLE {
+        PREAMBLE
+    } else {
+        SAMPLE_PREAMBLE
+    }
+}
+
+fn find_invalid(nums: &[u64]) -> Option<u64> {
+    let preamble = preamble(nums);
+
+    let mut i = preamble;
+    while i < nums.len() {
+        let allowed_nums = &nums[i - preamble..i];
+        let curr = &nums[i];
+
+        if !comb_is_valid(allowed_nums, curr) {
+            return Some(*curr);
+        }
+
+        i += 1;
+    }
+
+    None
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input)
+    }
+
+    fn part1(nums: &Self::Input) -> Result<impl Display> {
+        find_invalid(nums).ok_or_else(|| SolveError::no_solution("every number is valid").into())
+    }
+
+    fn part2(nums: &Self::Input) -> Result<impl Display> {
+        let invalid =
+            find_invalid(nums).ok_or_else(|| SolveError::no_solution("every number is valid"))?;
+
+        let mut len: usize = 2;
+        let mut i = 2;
+        while len < nums.len() {
+            while i < nums.len() {
+                let allowed_nums = &nums[i - len..i];
+                if allowed_nums.iter().sum::<u64>() == invalid {
+                    return Ok(
+                        allowed_nums.iter().max().unwrap() + allowed_nums.iter().min().unwrap()
+                    );
+                }
+
+                i += 1;
+            }
+
+            len += 1;
+            i = len;
+        }
+
+        Err(SolveError::no_solution(format!(
+            "no contiguous set of numbers sums up to {}",
+            invalid
+        ))
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 9, sample: [127, 62], real: [18272118, 2186361]);
+}
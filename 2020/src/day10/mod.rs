@@ -0,0 +1,63 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{memo::Memo, parse, solution::Solution};
+
+fn get_num_ways(joltages: &[u32], pos: usize, memo: &Memo<usize, u128>) -> u128 {
+    if pos == (joltages.len() - 1) {
+        return 1;
+    }
+
+    memo.get_or_insert_with(pos, || {
+        (pos + 1..joltages.len())
+            .take_while(|&i| joltages[i] - joltages[pos] <= 3)
+            .map(|i| get_num_ways(joltages, i, memo))
+            .sum()
+    })
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    /// The joltages of the adapters, sorted, together with the outlet and the device.
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut joltages: Vec<u32> = parse::lines(input)?;
+        joltages.sort();
+
+        let device = joltages.last().copied().unwrap_or(0) + 3;
+        joltages.insert(0, 0);
+        joltages.push(device);
+
+        Ok(joltages)
+    }
+
+    fn part1(joltages: &Self::Input) -> Result<impl Display> {
+        let mut diff_1: u32 = 0;
+        let mut diff_3: u32 = 0;
+        let mut i: usize = 0;
+        while i < joltages.len() - 1 {
+            let curr = joltages[i];
+            let next = joltages[i + 1];
+            if next - curr == 1 {
+                diff_1 += 1;
+            } else if next - curr == 3 {
+                diff_3 += 1;
+            }
+
+            i += 1;
+        }
+
+        Ok(diff_1 * diff_3)
+    }
+
+    fn part2(joltages: &Self::Input) -> Result<impl Display> {
+        Ok(get_num_ways(joltages, 0, &Memo::new()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 10, sample: [220, 19208], real: [2775, 518344341716992]);
+}
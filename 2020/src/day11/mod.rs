@@ -1,13 +1,10 @@
-use std::str::FromStr;
+use std::{fmt::Display, str::FromStr};
 
 use anyhow::{Error, Result};
-
-// NOTE: For answer to part 1 -> use TOLERANCE = 4 and get_adjacent_occupied()
-//                          2 -> use TOLERANCE = 5 and get_adjacent_occupied_2()
-const TOLERANCE: usize = 5;
+use aoc_common::solution::Solution;
 
 #[derive(Clone, Debug, PartialEq)]
-enum Seat {
+pub enum Seat {
     Empty,
     Floor,
     Occupied,
@@ -68,42 +65,34 @@ impl FromStr for Seat {
             "." => Self::Floor,
             "#" => Self::Occupied,
 
-            _ => panic!("invalid seat type found!"),
+            _ => return Err(anyhow::format_err!("invalid seat: {}", s)),
         })
     }
 }
 
-fn main() -> Result<()> {
-    let mut rows: Vec<Vec<Seat>> = std::fs::read_to_string("./data/inputs/11.txt")?
-        .lines()
-        .map(|row| {
-            row.chars()
-                .map(|seat| {
-                    seat.to_string()
-                        .parse::<Seat>()
-                        .expect("the char is not a seat!")
-                })
-                .collect()
-        })
-        .collect();
-
+/// Lets people take and leave seats until nobody moves anymore, returning how many seats end up
+/// occupied. A person leaves once `tolerance` of the seats they see are occupied.
+fn settle(
+    mut rows: Vec<Vec<Seat>>,
+    tolerance: usize,
+    get_adjacent_occupied: fn(&[Vec<Seat>], usize, usize) -> Vec<bool>,
+) -> usize {
     let temp = &mut rows.clone();
     let mut prev: Vec<Vec<Seat>> = vec![];
     loop {
-        for i in 0..rows.len() {
-            for j in 0..rows[0].len() {
-                let occupied_seats = get_adjacent_occupied_2(&rows, i, j)
+        for (i, temp_row) in temp.iter_mut().enumerate() {
+            for (j, curr) in temp_row.iter_mut().enumerate() {
+                let occupied_seats = get_adjacent_occupied(&rows, i, j)
                     .iter()
                     .filter(|seat| **seat)
                     .count();
 
-                let curr = &mut temp[i][j];
                 if curr.is_floor() {
                     continue;
                 }
                 if curr.is_empty() && occupied_seats == 0 {
                     curr.occupy()
-                } else if curr.is_occupied() && occupied_seats >= TOLERANCE {
+                } else if curr.is_occupied() && occupied_seats >= tolerance {
                     curr.empty()
                 }
             }
@@ -117,64 +106,57 @@ fn main() -> Result<()> {
         prev = temp.clone();
     }
 
-    let unoccupied = rows
-        .iter()
-        .map::<Vec<bool>, fn(&Vec<Seat>) -> Vec<bool>>(|row| {
-            row.iter().map(|seat| seat.is_occupied()).collect()
-        })
-        .flatten()
+    rows.iter()
+        .flat_map(|row| row.iter().map(|seat| seat.is_occupied()))
         .filter(|seat| *seat)
-        .count();
-    println!("Answer -> {}", unoccupied);
-
-    Ok(())
+        .count()
 }
 
-// fn get_adjacent_occupied(rows: &Vec<Vec<Seat>>, row_id: usize, col_id: usize) -> Vec<bool> {
-//     let mut occupied_seats: Vec<bool> = vec![];
+fn get_adjacent_occupied(rows: &[Vec<Seat>], row_id: usize, col_id: usize) -> Vec<bool> {
+    let mut occupied_seats: Vec<bool> = vec![];
 
-//     // not top row
-//     if row_id != 0 {
-//         occupied_seats.push(rows[row_id - 1][col_id].is_occupied());
+    // not top row
+    if row_id != 0 {
+        occupied_seats.push(rows[row_id - 1][col_id].is_occupied());
 
-//         // not leftmost column
-//         if col_id != 0 {
-//             occupied_seats.push(rows[row_id - 1][col_id - 1].is_occupied())
-//         }
+        // not leftmost column
+        if col_id != 0 {
+            occupied_seats.push(rows[row_id - 1][col_id - 1].is_occupied())
+        }
 
-//         // not rightmost column
-//         if col_id != rows[row_id].len() - 1 {
-//             occupied_seats.push(rows[row_id - 1][col_id + 1].is_occupied())
-//         }
-//     }
+        // not rightmost column
+        if col_id != rows[row_id].len() - 1 {
+            occupied_seats.push(rows[row_id - 1][col_id + 1].is_occupied())
+        }
+    }
 
-//     // not bottom row
-//     if row_id != rows.len() - 1 {
-//         occupied_seats.push(rows[row_id + 1][col_id].is_occupied());
+    // not bottom row
+    if row_id != rows.len() - 1 {
+        occupied_seats.push(rows[row_id + 1][col_id].is_occupied());
 
-//         // not leftmost column
-//         if col_id != 0 {
-//             occupied_seats.push(rows[row_id + 1][col_id - 1].is_occupied())
-//         }
+        // not leftmost column
+        if col_id != 0 {
+            occupied_seats.push(rows[row_id + 1][col_id - 1].is_occupied())
+        }
 
-//         // not rightmost column
-//         if col_id != rows[row_id].len() - 1 {
-//             occupied_seats.push(rows[row_id + 1][col_id + 1].is_occupied())
-//         }
-//     }
+        // not rightmost column
+        if col_id != rows[row_id].len() - 1 {
+            occupied_seats.push(rows[row_id + 1][col_id + 1].is_occupied())
+        }
+    }
 
-//     // not leftmost column
-//     if col_id != 0 {
-//         occupied_seats.push(rows[row_id][col_id - 1].is_occupied())
-//     }
+    // not leftmost column
+    if col_id != 0 {
+        occupied_seats.push(rows[row_id][col_id - 1].is_occupied())
+    }
 
-//     // not rightmost column
-//     if col_id != rows[row_id].len() - 1 {
-//         occupied_seats.push(rows[row_id][col_id + 1].is_occupied())
-//     }
+    // not rightmost column
+    if col_id != rows[row_id].len() - 1 {
+        occupied_seats.push(rows[row_id][col_id + 1].is_occupied())
+    }
 
-//     occupied_seats
-// }
+    occupied_seats
+}
 
 fn get_adjacent_occupied_2(rows: &[Vec<Seat>], row_id: usize, col_id: usize) -> Vec<bool> {
     let mut occupied_seats: Vec<bool> = vec![];
@@ -212,8 +194,7 @@ fn get_adjacent_occupied_2(rows: &[Vec<Seat>], row_id: usize, col_id: usize) ->
     }
 
     // all seats towards right
-    for i in col_id + 1..rows[0].len() {
-        let curr = &rows[row_id][i];
+    for curr in &rows[row_id][col_id + 1..] {
         if curr.is_floor() {
             continue;
         }
@@ -341,3 +322,29 @@ fn get_adjacent_occupied_2(rows: &[Vec<Seat>], row_id: usize, col_id: usize) ->
 
     occupied_seats
 }
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Vec<Seat>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|row| row.chars().map(|seat| seat.to_string().parse()).collect())
+            .collect()
+    }
+
+    fn part1(rows: &Self::Input) -> Result<impl Display> {
+        Ok(settle(rows.clone(), 4, get_adjacent_occupied))
+    }
+
+    fn part2(rows: &Self::Input) -> Result<impl Display> {
+        Ok(settle(rows.clone(), 5, get_adjacent_occupied_2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 11, sample: [37, 26], real: [2249, 2023]);
+}
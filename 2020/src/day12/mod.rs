@@ -1,9 +1,10 @@
-use std::str::FromStr;
+use std::{fmt::Display, str::FromStr};
 
-use anyhow::{Error, Result};
+use anyhow::{anyhow, ensure, Context, Error, Result};
+use aoc_common::{parse, solution::Solution};
 
 #[derive(Debug)]
-enum Instruction {
+pub enum Instruction {
     North(i32),
     South(i32),
     East(i32),
@@ -19,8 +20,19 @@ impl FromStr for Instruction {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let instruction: char = s.chars().next().unwrap();
-        let val: i32 = s[1..].parse().expect("val is not a number");
+        let mut chars = s.chars();
+        let instruction: char = chars.next().ok_or_else(|| anyhow!("empty instruction"))?;
+        let val: i32 = chars
+            .as_str()
+            .parse()
+            .with_context(|| format!("invalid value in {:?}", s))?;
+        if matches!(instruction, 'L' | 'R') {
+            ensure!(
+                val % 90 == 0,
+                "can only turn by multiples of 90 degrees, not {}",
+                val
+            );
+        }
         match instruction {
             'N' => Ok(Self::North(val)),
             'S' => Ok(Self::South(val)),
@@ -32,7 +44,7 @@ impl FromStr for Instruction {
             'L' => Ok(Self::Left(val)),
             'R' => Ok(Self::Right(val)),
 
-            _ => Err(anyhow::format_err!("invalid instruction provided!")),
+            _ => Err(anyhow!("invalid instruction {:?}", s)),
         }
     }
 }
@@ -67,7 +79,7 @@ impl Pos {
                 Instruction::East(_) => self.ew += val,
                 Instruction::West(_) => self.ew -= val,
 
-                _ => panic!("invalid orientation"),
+                _ => unreachable!("the ship only ever faces north, south, east or west"),
             },
 
             Instruction::Left(val) => {
@@ -110,7 +122,7 @@ impl Pos {
                         }
                     }
 
-                    _ => panic!("invalid orientation"),
+                    _ => unreachable!("the ship only ever faces north, south, east or west"),
                 }
             }
 
@@ -154,7 +166,7 @@ impl Pos {
                         }
                     }
 
-                    _ => panic!("invalid orientation"),
+                    _ => unreachable!("the ship only ever faces north, south, east or west"),
                 }
             }
         }
@@ -167,7 +179,7 @@ impl Pos {
                 self.ew += val * waypoint_pos.ew;
             }
 
-            _ => panic!("invalid instruction!"),
+            _ => unreachable!("the instruction does not move the ship"),
         }
     }
 
@@ -207,40 +219,52 @@ impl Pos {
                 }
             }
 
-            _ => panic!("invalid instruction!"),
+            _ => unreachable!("the instruction does not turn the waypoint"),
         }
     }
 }
 
-fn main() -> Result<()> {
-    let instructions: Vec<Instruction> = std::fs::read_to_string("./data/inputs/12.txt")?
-        .lines()
-        .map(|line| line.parse().expect("line is not an instruction"))
-        .collect();
-
-    let mut pos = Pos::new();
-    instructions
-        .iter()
-        .for_each(|instruction| pos.change_pos(instruction));
-    println!("Part 1 -> {}", pos.ns.abs() + pos.ew.abs());
-
-    let mut ship_pos = Pos::new();
-    let mut waypoint_pos = Pos {
-        ns: 1,
-        ew: 10,
-        orientation: Instruction::East(0),
-    };
-    instructions
-        .iter()
-        .for_each(|instruction| match *instruction {
-            Instruction::Forward(_) => ship_pos.change_pos_2(instruction, &waypoint_pos),
-
-            Instruction::Left(_) => waypoint_pos.change_pos_2_2(instruction),
-            Instruction::Right(_) => waypoint_pos.change_pos_2_2(instruction),
-
-            _ => waypoint_pos.change_pos(instruction),
-        });
-    println!("Part 2 -> {}", ship_pos.ns.abs() + ship_pos.ew.abs());
-
-    Ok(())
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<impl Display> {
+        let mut pos = Pos::new();
+        instructions
+            .iter()
+            .for_each(|instruction| pos.change_pos(instruction));
+
+        Ok(pos.ns.abs() + pos.ew.abs())
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<impl Display> {
+        let mut ship_pos = Pos::new();
+        let mut waypoint_pos = Pos {
+            ns: 1,
+            ew: 10,
+            orientation: Instruction::East(0),
+        };
+        instructions
+            .iter()
+            .for_each(|instruction| match *instruction {
+                Instruction::Forward(_) => ship_pos.change_pos_2(instruction, &waypoint_pos),
+
+                Instruction::Left(_) => waypoint_pos.change_pos_2_2(instruction),
+                Instruction::Right(_) => waypoint_pos.change_pos_2_2(instruction),
+
+                _ => waypoint_pos.change_pos(instruction),
+            });
+
+        Ok(ship_pos.ns.abs() + ship_pos.ew.abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 12, sample: [25, 286], real: [1010, 52742]);
 }
@@ -0,0 +1,63 @@
+use std::{collections::HashMap, fmt::Display};
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::{error::SolveError, math::crt, solution::Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    /// The arrival time and the buses, with `0` for the ones that are out of service.
+    type Input = (u128, Vec<u128>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (arrival_time, buses) = input
+            .split_once('\n')
+            .ok_or_else(|| anyhow!("Invalid notes: {}", input))?;
+        let arrival_time: u128 = arrival_time
+            .parse()
+            .context("the arrival time is not a number")?;
+        let buses: Vec<u128> = buses
+            .trim_end()
+            .split(',')
+            .map(|bus| if bus == "x" { "0" } else { bus })
+            .map(|bus| bus.parse().context("bus no is not a number"))
+            .collect::<Result<_>>()?;
+
+        Ok((arrival_time, buses))
+    }
+
+    fn part1((arrival_time, buses): &Self::Input) -> Result<impl Display> {
+        let arrival_time = *arrival_time;
+        let mut times: HashMap<u128, u128> = HashMap::new();
+        for bus in buses.iter() {
+            if *bus != 0 {
+                times.insert(*bus, ((arrival_time / *bus) + 1) * *bus);
+            }
+        }
+
+        let (bus, departure_time) = times
+            .iter()
+            .min_by_key(|(_, time)| *time)
+            .ok_or_else(|| anyhow!("there are no buses"))?;
+        Ok((*departure_time - arrival_time) * *bus)
+    }
+
+    fn part2((_, buses): &Self::Input) -> Result<impl Display> {
+        // Bus `bus` has to leave `i` minutes after the timestamp, so the timestamp is -i modulo
+        // `bus`
+        let congruences = buses
+            .iter()
+            .enumerate()
+            .filter(|(_, bus)| **bus != 0)
+            .map(|(i, bus)| (-(i as i128), *bus as i128));
+        let (time, _) =
+            crt(congruences).ok_or_else(|| SolveError::no_solution("the buses never line up"))?;
+
+        Ok(time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 13, sample: [295, 1068781], real: [4135, 640856202464541]);
+}
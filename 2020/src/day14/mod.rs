@@ -0,0 +1,171 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, ensure, Context, Error, Result};
+use aoc_common::solution::Solution;
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct Mask {
+    mask: String,
+}
+
+impl FromStr for Mask {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, val) = s
+            .split_once(" = ")
+            .ok_or_else(|| anyhow!("expected an assignment in {:?}", s))?;
+        if name != "mask" {
+            return Err(anyhow::format_err!(
+                "the given string does not specify a mask!"
+            ));
+        }
+        ensure!(
+            val.len() == 36 && val.chars().all(|bit| matches!(bit, 'X' | '0' | '1')),
+            "{:?} is not a mask of 36 Xs, 0s and 1s",
+            val
+        );
+
+        Ok(Mask {
+            mask: val.to_string(),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct MemVal {
+    addr: u64,
+    masked_addr: Vec<u64>,
+    val: u64,
+    masked_val: u64,
+}
+
+impl MemVal {
+    fn apply_mask(&mut self, mask: &Mask) {
+        let mut str_val = format!("{:0>36}", format!("{:b}", self.val));
+        for (i, bit) in mask.mask.chars().enumerate() {
+            if bit == 'X' {
+                continue;
+            }
+
+            str_val.replace_range(i..i + 1, &bit.to_string())
+        }
+
+        self.masked_val =
+            u64::from_str_radix(&str_val, 2).expect("a masked value is only made up of 0s and 1s");
+    }
+
+    fn apply_mask_2(&mut self, mask: &Mask) {
+        let mut str_val = format!("{:0>36}", format!("{:b}", self.addr));
+        for (i, bit) in mask.mask.chars().enumerate() {
+            if bit == '0' {
+                continue;
+            }
+
+            str_val.replace_range(i..i + 1, &bit.to_string())
+        }
+
+        let mut temp: Vec<String> = vec![str_val];
+        while !temp.is_empty() {
+            if let Some(mut val) = temp.pop() {
+                if let Some(idx) = val.find('X') {
+                    val.replace_range(idx..idx + 1, "1");
+                    temp.push(val.clone());
+                    val.replace_range(idx..idx + 1, "0");
+                    temp.push(val);
+                } else {
+                    self.masked_addr.push(
+                        u64::from_str_radix(&val, 2)
+                            .expect("a masked address is only made up of 0s and 1s"),
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for MemVal {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, val) = s
+            .split_once(" = ")
+            .ok_or_else(|| anyhow!("expected an assignment in {:?}", s))?;
+        let Some(addr) = name
+            .strip_prefix("mem[")
+            .and_then(|addr| addr.strip_suffix(']'))
+        else {
+            return Err(anyhow::format_err!(
+                "the given string does not specify a mem val!"
+            ));
+        };
+
+        Ok(MemVal {
+            addr: addr
+                .parse()
+                .with_context(|| format!("invalid address in {:?}", s))?,
+            masked_addr: vec![],
+            val: val
+                .parse()
+                .with_context(|| format!("invalid value in {:?}", s))?,
+            masked_val: 0,
+        })
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Result<impl Display> {
+        let mut mem: HashMap<u64, u64> = HashMap::new();
+
+        let mut mask: Mask = Mask {
+            mask: "".to_string(),
+        };
+        for line in lines.iter() {
+            if line.contains("mask") {
+                mask = line.parse()?;
+                continue;
+            }
+
+            let mut val: MemVal = line.parse()?;
+            val.apply_mask(&mask);
+            mem.insert(val.addr, val.masked_val);
+        }
+
+        Ok(mem.values().sum::<u64>())
+    }
+
+    fn part2(lines: &Self::Input) -> Result<impl Display> {
+        let mut mem: HashMap<u64, u64> = HashMap::new();
+
+        let mut mask: Mask = Mask {
+            mask: "".to_string(),
+        };
+        for line in lines.iter() {
+            if line.contains("mask") {
+                mask = line.parse()?;
+                continue;
+            }
+
+            let mut val: MemVal = line.parse()?;
+            val.apply_mask_2(&mask);
+            for addr in val.masked_addr.iter() {
+                mem.insert(*addr, val.val);
+            }
+        }
+
+        Ok(mem.values().sum::<u64>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 14, sample: [51, 208], real: [11612740949946, 3394509207186]);
+}
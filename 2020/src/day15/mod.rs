@@ -0,0 +1,63 @@
+use std::{collections::HashMap, fmt::Display};
+
+use anyhow::{anyhow, Result};
+use aoc_common::solution::Solution;
+
+fn get_nth_num(nums: &[usize], n: usize) -> usize {
+    let mut counts: HashMap<usize, Vec<usize>> = HashMap::new();
+
+    nums.iter().enumerate().for_each(|(i, num)| {
+        counts.insert(*num, vec![(i + 1)]);
+    });
+
+    let mut prev_num = nums[nums.len() - 1];
+    for i in nums.len()..n {
+        if i == 1 {
+            prev_num = 0;
+            continue;
+        }
+
+        if let Some(val) = counts.get_mut(&prev_num) {
+            prev_num = i - val[val.len() - 1];
+            val.push(i);
+            continue;
+        }
+
+        counts.insert(prev_num, vec![i]);
+        prev_num = 0;
+    }
+
+    prev_num
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let nums: Vec<usize> = input
+            .trim()
+            .split(',')
+            .map(|num| num.parse())
+            .collect::<Result<_, _>>()?;
+        if nums.is_empty() {
+            return Err(anyhow!("there are no starting numbers"));
+        }
+
+        Ok(nums)
+    }
+
+    fn part1(nums: &Self::Input) -> Result<impl Display> {
+        Ok(get_nth_num(nums, 2020))
+    }
+
+    fn part2(nums: &Self::Input) -> Result<impl Display> {
+        Ok(get_nth_num(nums, 30_000_000))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 15, sample: [1, 2578], real: [1015, 201]);
+}
@@ -0,0 +1,134 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, ensure, Result};
+use aoc_common::{
+    combinator::{self, lines, literal, separated, uint, word, Parser},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Rule {
+    name: String,
+    allowed_vals: Vec<u32>,
+}
+
+#[derive(Debug)]
+pub struct Ticket {
+    vals: Vec<u32>,
+}
+
+/// `<name>: <min>-<max> or <min>-<max>`, where the name can be several words.
+fn rule<'a>() -> impl Parser<'a, Rule> {
+    let name = separated(word(), literal(" ")).map(|words| words.join(" "));
+    let range = (uint(), literal("-"), uint()).map(|(min, _, max)| min..=max);
+
+    (name, literal(": "), separated(range, literal(" or "))).map(|(name, _, ranges)| Rule {
+        name,
+        allowed_vals: ranges.into_iter().flatten().collect(),
+    })
+}
+
+fn ticket<'a>() -> impl Parser<'a, Ticket> {
+    separated(uint(), literal(",")).map(|vals| Ticket { vals })
+}
+
+fn is_valid_rule(vals: &[u32], rule: &Rule) -> bool {
+    for val in vals.iter() {
+        if !rule.allowed_vals.contains(val) {
+            return false;
+        }
+    }
+
+    true
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    /// The rules, my ticket and the nearby tickets.
+    type Input = (Vec<Rule>, Ticket, Vec<Ticket>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rules, _, my_ticket, _, other_tickets) = combinator::parse(
+            input,
+            (
+                lines(rule()),
+                literal("\n\nyour ticket:\n"),
+                ticket(),
+                literal("\n\nnearby tickets:\n"),
+                lines(ticket()),
+            ),
+        )?;
+        ensure!(
+            other_tickets
+                .iter()
+                .all(|ticket| ticket.vals.len() == my_ticket.vals.len()),
+            "the nearby tickets do not all have as many fields as mine"
+        );
+
+        Ok((rules, my_ticket, other_tickets))
+    }
+
+    fn part1((rules, my_ticket, other_tickets): &Self::Input) -> Result<impl Display> {
+        let allowed_vals: Vec<&u32> = rules.iter().flat_map(|rule| &rule.allowed_vals).collect();
+        let mut invalid_vals: Vec<&u32> = vec![];
+        my_ticket.vals.iter().for_each(|val| {
+            if !allowed_vals.contains(&val) {
+                invalid_vals.push(val)
+            }
+        });
+        other_tickets.iter().for_each(|ticket| {
+            ticket.vals.iter().for_each(|val| {
+                if !allowed_vals.contains(&val) {
+                    invalid_vals.push(val)
+                }
+            })
+        });
+
+        Ok(invalid_vals.iter().fold(0, |acc, val| acc + (**val)))
+    }
+
+    fn part2((rules, my_ticket, other_tickets): &Self::Input) -> Result<impl Display> {
+        let allowed_vals: Vec<&u32> = rules.iter().flat_map(|rule| &rule.allowed_vals).collect();
+        let other_tickets: Vec<&Ticket> = other_tickets
+            .iter()
+            .filter(|ticket| ticket.vals.iter().all(|val| allowed_vals.contains(&val)))
+            .collect();
+
+        // the rules every field could be following, narrowed down by fixing the fields that can
+        // only be following a single rule
+        let mut candidates: Vec<Vec<usize>> = (0..my_ticket.vals.len())
+            .map(|i| {
+                let mut vals: Vec<u32> =
+                    other_tickets.iter().map(|ticket| ticket.vals[i]).collect();
+                vals.push(my_ticket.vals[i]);
+                (0..rules.len())
+                    .filter(|&rule| is_valid_rule(&vals, &rules[rule]))
+                    .collect()
+            })
+            .collect();
+        let mut order: Vec<Option<usize>> = vec![None; candidates.len()];
+        while let Some(i) = candidates.iter().position(|rules| rules.len() == 1) {
+            let rule = candidates[i][0];
+            order[i] = Some(rule);
+            for rules in candidates.iter_mut() {
+                rules.retain(|&r| r != rule);
+            }
+        }
+
+        let mut ans: u64 = 1;
+        for (i, rule) in order.into_iter().enumerate() {
+            let rule = rule.ok_or_else(|| anyhow!("could not work out the rule of field {}", i))?;
+            if rules[rule].name.contains("depart") {
+                ans *= my_ticket.vals[i] as u64;
+            }
+        }
+
+        Ok(ans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 16, sample: [71, 1], real: [21980, 1439429522627]);
+}
@@ -1 +1,41 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
 
+use aoc_common::solution::{Day, Year};
+
+pub const YEAR: Year = Year {
+    year: 2020,
+    dir: concat!(env!("CARGO_MANIFEST_DIR"), "/src"),
+    days: &[
+        Day::new::<day01::Day01>(1),
+        Day::new::<day02::Day02>(2),
+        Day::new::<day03::Day03>(3),
+        Day::new::<day04::Day04>(4),
+        Day::new::<day05::Day05>(5),
+        Day::new::<day06::Day06>(6),
+        Day::new::<day07::Day07>(7),
+        Day::new::<day08::Day08>(8),
+        Day::new::<day09::Day09>(9),
+        Day::new::<day10::Day10>(10),
+        Day::new::<day11::Day11>(11),
+        Day::new::<day12::Day12>(12),
+        Day::new::<day13::Day13>(13),
+        Day::new::<day14::Day14>(14),
+        Day::new::<day15::Day15>(15),
+        Day::new::<day16::Day16>(16),
+    ],
+};
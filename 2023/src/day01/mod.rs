@@ -0,0 +1,87 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, Result};
+use aoc_common::solution::Solution;
+
+fn part1(input: &str) -> u32 {
+    let lines = input.lines();
+    let mut sum = 0;
+
+    for line in lines {
+        let mut num1 = 0;
+        let mut num2 = 0;
+
+        // Start iterating from the *start* of the line and stop when you find the 1st digit
+        for ch in line.chars() {
+            if let Some(d) = ch.to_digit(10) {
+                num1 = d;
+                break;
+            }
+        }
+
+        // Start iterating from the *end* of the line and stop when you find the 1st digit
+        for ch in line.chars().rev() {
+            if let Some(d) = ch.to_digit(10) {
+                num2 = d;
+                break;
+            }
+        }
+
+        sum += num1 * 10 + num2;
+    }
+
+    sum
+}
+
+const DIGITS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn part2(input: &str) -> Result<u32> {
+    let mut sum = 0;
+
+    for line in input.lines() {
+        let mut num1 = None;
+        let mut num2 = 0;
+        for (i, ch) in line.char_indices() {
+            if let Some(d) = ch.to_digit(10) {
+                num1 = num1.or(Some(d));
+                num2 = d;
+            } else {
+                for (j, dig) in DIGITS.iter().enumerate() {
+                    if line[i..].starts_with(*dig) {
+                        num1 = num1.or(Some((j as u32) + 1));
+                        num2 = (j as u32) + 1;
+                    }
+                }
+            }
+        }
+        let num1 = num1.ok_or_else(|| anyhow!("there are no digits in {:?}", line))?;
+        sum += num1 * 10 + num2;
+    }
+
+    Ok(sum)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 1, sample: [142, 281], real: [55002, 55093]);
+}
@@ -0,0 +1,127 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    combinator::{self, lines, literal, separated, uint, word, Parser},
+    solution::Solution,
+};
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Game {
+    id: u32,
+    max_red: u32,
+    max_green: u32,
+    max_blue: u32,
+}
+
+impl Game {
+    fn add_cubes(&mut self, (num_cubes, color): (u32, Color)) {
+        let max = match color {
+            Color::Red => &mut self.max_red,
+            Color::Green => &mut self.max_green,
+            Color::Blue => &mut self.max_blue,
+        };
+        if *max < num_cubes {
+            *max = num_cubes
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl TryFrom<&str> for Color {
+    type Error = anyhow::Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        match input {
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            "blue" => Ok(Color::Blue),
+            _ => Err(anyhow!("expected a color, found {:?}", input)),
+        }
+    }
+}
+
+/// `Game <id>: ` followed by subsets of cubes such as `3 blue, 4 red`, separated by `; `.
+fn game<'a>() -> impl Parser<'a, Game> {
+    let cubes = (uint(), literal(" "), word().try_map(Color::try_from))
+        .map(|(num_cubes, _, color)| (num_cubes, color));
+    let subset = separated(cubes, literal(", "));
+
+    (
+        literal("Game "),
+        uint(),
+        literal(": "),
+        separated(subset, literal("; ")),
+    )
+        .map(|(_, id, _, subsets)| {
+            let mut game = Game {
+                id,
+                ..Game::default()
+            };
+            for cubes in subsets.into_iter().flatten() {
+                game.add_cubes(cubes);
+            }
+            game
+        })
+}
+
+fn part1(games: &[Game]) -> u32 {
+    let required_game = Game {
+        id: 0,
+        max_red: 12,
+        max_green: 13,
+        max_blue: 14,
+    };
+
+    let mut sum = 0;
+
+    for game in games {
+        if !(game.max_red > required_game.max_red
+            || game.max_green > required_game.max_green
+            || game.max_blue > required_game.max_blue)
+        {
+            sum += game.id;
+        }
+    }
+
+    sum
+}
+
+fn part2(games: &[Game]) -> u32 {
+    let mut sum = 0;
+
+    for game in games {
+        sum += game.max_red * game.max_green * game.max_blue
+    }
+
+    sum
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        combinator::parse(input, lines(game()))
+    }
+
+    fn part1(games: &Self::Input) -> Result<impl Display> {
+        Ok(part1(games))
+    }
+
+    fn part2(games: &Self::Input) -> Result<impl Display> {
+        Ok(part2(games))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 2, sample: [8, 2286], real: [2679, 77607]);
+}
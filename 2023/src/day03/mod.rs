@@ -0,0 +1,230 @@
+use std::fmt::Display;
+
+use anyhow::{ensure, Result};
+use aoc_common::{
+    combinator::{self, cells, lines},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    is_symbol: bool,
+    is_gear: bool,
+    is_digit: bool,
+    value: u32,
+    connected_parts: Option<Vec<u32>>,
+}
+
+impl Node {
+    /// Digits, `.` for nothing and any other punctuation for a symbol.
+    fn from_char(c: char) -> Option<Self> {
+        let node = if let Some(d) = c.to_digit(10) {
+            Node {
+                is_symbol: false,
+                is_gear: false,
+                is_digit: true,
+                value: d,
+                connected_parts: None,
+            }
+        } else if c == '.' {
+            Node {
+                is_symbol: false,
+                is_gear: false,
+                is_digit: false,
+                value: 0,
+                connected_parts: None,
+            }
+        } else if c == '*' {
+            Node {
+                is_symbol: true,
+                is_gear: true,
+                is_digit: false,
+                value: 0,
+                connected_parts: Some(vec![]),
+            }
+        } else if c.is_ascii_punctuation() {
+            Node {
+                is_symbol: true,
+                is_gear: false,
+                is_digit: false,
+                value: 0,
+                connected_parts: None,
+            }
+        } else {
+            return None;
+        };
+
+        Some(node)
+    }
+}
+
+pub type Engine = Vec<Vec<Node>>;
+
+fn is_node_part_num(
+    i: usize,
+    j: usize,
+    engine: &Engine,
+    engine_dimensions: &(usize, usize),
+) -> bool {
+    let [y, x] = [i as isize, j as isize];
+    for ny in (y - 1)..=(y + 1) {
+        if ny < 0 || ny >= engine_dimensions.1 as isize {
+            continue;
+        }
+
+        for nx in (x - 1)..=(x + 1) {
+            if nx < 0 || nx >= engine_dimensions.0 as isize {
+                continue;
+            }
+
+            let [ny, nx] = [ny as usize, nx as usize];
+            if engine[ny][nx].is_symbol {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+fn find_gear_connected_to_node(
+    i: usize,
+    j: usize,
+    engine: &Engine,
+    engine_dimensions: &(usize, usize),
+) -> Option<(usize, usize)> {
+    let [y, x] = [i as isize, j as isize];
+    for ny in (y - 1)..=(y + 1) {
+        if ny < 0 || ny >= engine_dimensions.1 as isize {
+            continue;
+        }
+
+        for nx in (x - 1)..=(x + 1) {
+            if nx < 0 || nx >= engine_dimensions.0 as isize {
+                continue;
+            }
+
+            let [ny, nx] = [ny as usize, nx as usize];
+            if engine[ny][nx].is_symbol && engine[ny][nx].is_gear {
+                return Some((ny, nx));
+            }
+        }
+    }
+
+    None
+}
+
+fn part1(engine: &Engine, engine_dimensions: &(usize, usize)) -> u32 {
+    let mut sum = 0;
+    for (i, line) in engine.iter().enumerate() {
+        let mut num = 0;
+        let mut is_part_num = false;
+        for (j, node) in line.iter().enumerate() {
+            if !node.is_digit {
+                if num != 0 {
+                    if is_part_num {
+                        sum += num;
+                        is_part_num = false;
+                    }
+                    num = 0;
+                }
+
+                continue;
+            }
+
+            num = num * 10 + node.value;
+            if !is_part_num {
+                is_part_num = is_node_part_num(i, j, engine, engine_dimensions);
+            }
+
+            if j == engine_dimensions.0 - 1 && is_part_num && num != 0 {
+                sum += num;
+            }
+        }
+    }
+
+    sum
+}
+
+fn part2(engine: &Engine, engine_dimensions: &(usize, usize)) -> u32 {
+    let mut sum = 0;
+    let mut engine_clone = engine.clone();
+
+    for (i, line) in engine.iter().enumerate() {
+        let mut num = 0;
+        let mut gear_pos: Option<(usize, usize)> = None;
+        for (j, node) in line.iter().enumerate() {
+            if !node.is_digit {
+                if num != 0 {
+                    if let Some((y, x)) = gear_pos {
+                        if let Some(parts) = engine_clone[y][x].connected_parts.as_mut() {
+                            parts.push(num);
+                        }
+                        gear_pos = None;
+                    }
+                    num = 0;
+                }
+
+                continue;
+            }
+
+            num = num * 10 + node.value;
+            if gear_pos.is_none() {
+                gear_pos = find_gear_connected_to_node(i, j, engine, engine_dimensions);
+            }
+
+            if let Some((y, x)) = gear_pos.filter(|_| j == engine_dimensions.0 - 1 && num != 0) {
+                if let Some(parts) = engine_clone[y][x].connected_parts.as_mut() {
+                    parts.push(num);
+                }
+            }
+        }
+    }
+
+    for line in engine_clone.iter() {
+        for node in line.iter() {
+            if let Some(parts) = node.connected_parts.as_ref().filter(|_| node.is_gear) {
+                if parts.len() == 2 {
+                    sum += parts.iter().product::<u32>();
+                }
+            }
+        }
+    }
+
+    sum
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    /// The engine along with its dimensions as `(width, height)`.
+    type Input = (Engine, (usize, usize));
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let engine: Engine = combinator::parse(
+            input,
+            lines(cells(Node::from_char, "a digit, '.' or a symbol")),
+        )?;
+        ensure!(
+            engine.iter().all(|row| row.len() == engine[0].len()),
+            "the rows of the engine are not all equally long"
+        );
+
+        let engine_dimensions = (engine[0].len(), engine.len());
+
+        Ok((engine, engine_dimensions))
+    }
+
+    fn part1((engine, engine_dimensions): &Self::Input) -> Result<impl Display> {
+        Ok(part1(engine, engine_dimensions))
+    }
+
+    fn part2((engine, engine_dimensions): &Self::Input) -> Result<impl Display> {
+        Ok(part2(engine, engine_dimensions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 3, sample: [4361, 467835], real: [520135, 72514855]);
+}
@@ -0,0 +1,138 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{
+    combinator::{self, lines, literal, separated, spaces, uint, Failure, Parser},
+    error::SolveError,
+    solution::Solution,
+};
+
+#[derive(Debug, Clone)]
+pub struct Card {
+    winning_nums: Vec<u32>,
+    my_nums: Vec<u32>,
+}
+
+/// The spaces between two numbers, which may be more than one to line the numbers up.
+fn gap<'a>() -> impl Parser<'a, ()> {
+    |input: &'a str| {
+        let rest = input.trim_start_matches(' ');
+        if rest.len() < input.len() && rest.starts_with(|c: char| c.is_ascii_digit()) {
+            Ok(((), rest))
+        } else {
+            Err(Failure {
+                at: input,
+                message: "expected another number".into(),
+            })
+        }
+    }
+}
+
+/// `Card <id>: ` followed by the winning numbers and my numbers, separated by ` | `.
+fn card<'a>() -> impl Parser<'a, Card> {
+    let numbers = || separated(uint(), gap());
+
+    (
+        (
+            literal("Card"),
+            spaces(),
+            uint::<u32>(),
+            literal(":"),
+            spaces(),
+        ),
+        numbers(),
+        literal(" |"),
+        spaces(),
+        numbers(),
+    )
+        .map(|(_, winning_nums, _, _, my_nums)| Card {
+            winning_nums,
+            my_nums,
+        })
+}
+
+fn part1(cards: &[Card]) -> u32 {
+    cards.iter().fold(0, |acc, card| {
+        let num_wins = card.my_nums.iter().fold(0, |acc, num| {
+            if card.winning_nums.contains(num) {
+                acc + 1
+            } else {
+                acc
+            }
+        });
+
+        if num_wins == 0 {
+            acc
+        } else {
+            acc + 2_u32.pow(num_wins - 1)
+        }
+    })
+}
+
+fn part2_helper(curr_id: usize, num_wins: u32, cards: &[Card]) -> Result<u32> {
+    let mut num_cards = 1;
+
+    let won = cards
+        .get(curr_id + 1..=curr_id + (num_wins as usize))
+        .ok_or_else(|| SolveError::no_solution("a card wins copies of cards past the last one"))?;
+    for (card, id) in won.iter().zip(curr_id + 1..) {
+        let num_wins = card.my_nums.iter().fold(0, |acc, num| {
+            if card.winning_nums.contains(num) {
+                acc + 1
+            } else {
+                acc
+            }
+        });
+
+        if num_wins == 0 {
+            num_cards += 1;
+            continue;
+        }
+
+        num_cards += part2_helper(id, num_wins, cards)?;
+    }
+
+    Ok(num_cards)
+}
+
+fn part2(cards: &[Card]) -> Result<u32> {
+    cards.iter().enumerate().try_fold(0, |acc, (id, card)| {
+        let num_wins = card.my_nums.iter().fold(0, |acc, num| {
+            if card.winning_nums.contains(num) {
+                acc + 1
+            } else {
+                acc
+            }
+        });
+
+        if num_wins == 0 {
+            return Ok(acc + 1); // add 1 for the original card
+        }
+
+        // NOTE: here we do not add 1 for the original card as that is being taken care of in the part2_helper func
+        Ok(acc + part2_helper(id, num_wins, cards)?)
+    })
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        combinator::parse(input, lines(card()))
+    }
+
+    fn part1(cards: &Self::Input) -> Result<impl Display> {
+        Ok(part1(cards))
+    }
+
+    fn part2(cards: &Self::Input) -> Result<impl Display> {
+        part2(cards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 4, sample: [13, 30], real: [27845, 9496801]);
+}
@@ -0,0 +1,170 @@
+use std::{fmt::Display, ops::Range};
+
+use anyhow::{ensure, Result};
+use aoc_common::{
+    combinator::{self, lines, literal, sections, separated, uint, word, Parser},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+struct SubMap {
+    src_start: u64,
+    dst_start: u64,
+    length: u64,
+}
+
+impl SubMap {
+    fn get_dst_for_src(&self, src: u64) -> Option<u64> {
+        // Ensure that src in within the map range
+        if !(src >= self.src_start && src < self.src_start + self.length) {
+            return None;
+        }
+
+        let dst = self.dst_start + (src - self.src_start);
+
+        Some(dst)
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    #[allow(unused)]
+    src: String,
+    #[allow(unused)]
+    dst: String,
+    sub_maps: Vec<SubMap>,
+}
+
+impl Map {
+    fn get_dst_for_src(&self, src: u64) -> u64 {
+        for sub_map in &self.sub_maps {
+            if let Some(dst) = sub_map.get_dst_for_src(src) {
+                return dst;
+            }
+        }
+
+        src
+    }
+
+    /// Maps whole ranges of sources at once, splitting a range wherever only a part of it is
+    /// covered by a sub map.
+    fn get_dst_ranges_for_src_ranges(&self, mut src_ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+        let mut dst_ranges = vec![];
+        for sub_map in &self.sub_maps {
+            let src_end = sub_map.src_start + sub_map.length;
+
+            let mut unmapped_ranges = vec![];
+            for range in src_ranges {
+                if range.start < sub_map.src_start {
+                    unmapped_ranges.push(range.start..range.end.min(sub_map.src_start));
+                }
+                if range.end > src_end {
+                    unmapped_ranges.push(range.start.max(src_end)..range.end);
+                }
+
+                let start = range.start.max(sub_map.src_start);
+                let end = range.end.min(src_end);
+                if start < end {
+                    dst_ranges.push(
+                        sub_map.dst_start + (start - sub_map.src_start)
+                            ..sub_map.dst_start + (end - sub_map.src_start),
+                    );
+                }
+            }
+
+            src_ranges = unmapped_ranges;
+        }
+
+        // Sources that are not covered by any sub map go to the same destination
+        dst_ranges.extend(src_ranges);
+        dst_ranges
+    }
+}
+
+fn find_loc_for_seed(seed: u64, maps: &[Map]) -> u64 {
+    let mut dst = seed;
+    for map in maps {
+        dst = map.get_dst_for_src(dst);
+    }
+
+    dst
+}
+
+fn part1(seeds: &[u64], maps: &[Map]) -> u64 {
+    seeds
+        .iter()
+        .map(|seed| find_loc_for_seed(*seed, maps))
+        .min()
+        .unwrap()
+}
+
+fn part2(seeds: &[u64], maps: &[Map]) -> Result<u64> {
+    ensure!(
+        seeds.len().is_multiple_of(2),
+        "the seeds do not come in pairs of a start and a length"
+    );
+
+    let mut ranges: Vec<Range<u64>> = seeds
+        .chunks(2)
+        .map(|pair| pair[0]..pair[0] + pair[1])
+        .collect();
+    for map in maps {
+        ranges = map.get_dst_ranges_for_src_ranges(ranges);
+    }
+
+    Ok(ranges
+        .iter()
+        .map(|range| range.start)
+        .min()
+        .unwrap_or_default())
+}
+
+/// `<src>-to-<dst> map:` followed by one line of `<dst start> <src start> <length>` per sub map.
+fn map<'a>() -> impl Parser<'a, Map> {
+    let sub_map = (uint(), literal(" "), uint(), literal(" "), uint()).map(
+        |(dst_start, _, src_start, _, length)| SubMap {
+            src_start,
+            dst_start,
+            length,
+        },
+    );
+
+    (
+        word(),
+        literal("-to-"),
+        word(),
+        literal(" map:\n"),
+        lines(sub_map),
+    )
+        .map(|(src, _, dst, _, sub_maps)| Map {
+            src: src.to_owned(),
+            dst: dst.to_owned(),
+            sub_maps,
+        })
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Vec<u64>, Vec<Map>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let seeds = (literal("seeds: "), separated(uint(), literal(" "))).map(|(_, seeds)| seeds);
+        let (seeds, _, maps) = combinator::parse(input, (seeds, literal("\n\n"), sections(map())))?;
+
+        Ok((seeds, maps))
+    }
+
+    fn part1((seeds, maps): &Self::Input) -> Result<impl Display> {
+        Ok(part1(seeds, maps))
+    }
+
+    fn part2((seeds, maps): &Self::Input) -> Result<impl Display> {
+        part2(seeds, maps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 5, sample: [35, 46], real: [261668924, 24261545]);
+}
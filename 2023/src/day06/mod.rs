@@ -0,0 +1,98 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, ensure, Context, Result};
+use aoc_common::{parse, solution::Solution};
+use itertools::Itertools;
+
+#[derive(Debug)]
+pub struct Races {
+    durations: Vec<u64>,
+    distances: Vec<u64>,
+}
+
+/// The number of ways to hold the button for long enough to beat the record.
+fn ways_to_win(duration: u64, distance: u64) -> u64 {
+    (1..duration).fold(0, |acc, time| {
+        if time * (duration - time) > distance {
+            acc + 1
+        } else {
+            acc
+        }
+    })
+}
+
+fn part1(races: &Races) -> u64 {
+    races
+        .durations
+        .iter()
+        .zip(&races.distances)
+        .map(|(&duration, &distance)| ways_to_win(duration, distance))
+        .product()
+}
+
+fn part2(races: &Races) -> Result<u64> {
+    // The numbers on each line are really one number with some spaces in it
+    let join = |numbers: &[u64]| {
+        numbers
+            .iter()
+            .join("")
+            .parse::<u64>()
+            .context("the race is too long")
+    };
+
+    Ok(ways_to_win(
+        join(&races.durations)?,
+        join(&races.distances)?,
+    ))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Races;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (durations, distances) = input
+            .trim_end()
+            .split_once('\n')
+            .ok_or_else(|| anyhow!("expected a line of times and a line of distances"))?;
+        let durations: Vec<u64> = parse::unsigned_integers(
+            durations
+                .strip_prefix("Time:")
+                .context("expected the times to start with \"Time:\"")?,
+        )
+        .separated_by(" ")
+        .collect::<Result<_>>()?;
+        let distances: Vec<u64> = parse::unsigned_integers(
+            distances
+                .strip_prefix("Distance:")
+                .context("expected the distances to start with \"Distance:\"")?,
+        )
+        .separated_by(" ")
+        .collect::<Result<_>>()?;
+        ensure!(
+            durations.len() == distances.len(),
+            "there are {} times but {} distances",
+            durations.len(),
+            distances.len()
+        );
+
+        Ok(Races {
+            durations,
+            distances,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 6, sample: [288, 71503], real: [1108800, 36919753]);
+}
@@ -0,0 +1,165 @@
+use std::{collections::HashMap, fmt::Display};
+
+use anyhow::Result;
+use aoc_common::{
+    combinator::{self, lines, literal, token, uint, Parser},
+    solution::Solution,
+};
+
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy)]
+enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Card {
+    /// `J` is read as a [`Card::Joker`] when `jokers` is set (part 2) and as a [`Card::Jack`]
+    /// otherwise (part 1).
+    fn new(input: char, jokers: bool) -> Option<Self> {
+        use Card::*;
+        let card = match input {
+            'A' => Ace,
+            'K' => King,
+            'Q' => Queen,
+            'J' if jokers => Joker,
+            'J' => Jack,
+            'T' => Ten,
+            '9' => Nine,
+            '8' => Eight,
+            '7' => Seven,
+            '6' => Six,
+            '5' => Five,
+            '4' => Four,
+            '3' => Three,
+            '2' => Two,
+            _ => return None,
+        };
+
+        Some(card)
+    }
+}
+
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone)]
+enum HandKind {
+    HighCard(Vec<Card>),     // => 1 + 1 + 1 + 1 + 1
+    OnePair(Vec<Card>),      // => 2 + 1 + 1 + 1
+    TwoPair(Vec<Card>),      // => 2 + 2 + 1
+    ThreeOfAKind(Vec<Card>), // => 3 + 1 + 1
+    FullHouse(Vec<Card>),    // => 3 + 2
+    FourOfAKind(Vec<Card>),  // => 4 + 1
+    FiveOfAKind(Vec<Card>),  // => 5
+}
+
+impl HandKind {
+    fn new(cards: Vec<Card>) -> Self {
+        use HandKind::*;
+
+        let num_jokers = cards.iter().filter(|c| **c == Card::Joker).count();
+        // we must return early if all cards are jokers as logic ahead fails if the cards vector becomes empty
+        if num_jokers == 5 {
+            return FiveOfAKind(cards);
+        }
+
+        let mut card_counter: HashMap<Card, u32> = HashMap::new();
+        for card in cards.iter() {
+            *card_counter.entry(*card).or_insert(0) += 1;
+        }
+
+        let counts: Vec<&u32> = card_counter.values().collect();
+        let kind = if counts.contains(&&5) {
+            FiveOfAKind(cards)
+        } else if counts.contains(&&4) {
+            FourOfAKind(cards)
+        } else if counts.contains(&&3) && counts.contains(&&2) {
+            FullHouse(cards)
+        } else if counts.contains(&&3) {
+            ThreeOfAKind(cards)
+        } else if counts.iter().filter(|v| ***v == 2).count() == 2 {
+            TwoPair(cards)
+        } else if counts.contains(&&2) {
+            OnePair(cards)
+        } else {
+            HighCard(cards)
+        };
+
+        match (kind, num_jokers) {
+            (FourOfAKind(cards), 1 | 4) | (FullHouse(cards), 2 | 3) => FiveOfAKind(cards),
+            (ThreeOfAKind(cards), 1 | 3) | (TwoPair(cards), 2) => FourOfAKind(cards),
+            (TwoPair(cards), 1) => FullHouse(cards),
+            (OnePair(cards), 1 | 2) => ThreeOfAKind(cards),
+            (HighCard(cards), 1) => OnePair(cards),
+            (kind, _) => kind,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Hand {
+    kind: HandKind,
+    bid: u32,
+}
+
+/// Five cards, a space and the bid, e.g. `32T3K 765`.
+fn hand<'a>(jokers: bool) -> impl Parser<'a, Hand> {
+    let cards = token().try_map(move |cards| {
+        let cards: Vec<Card> = cards
+            .chars()
+            .map(|c| Card::new(c, jokers).ok_or_else(|| format!("expected a card, found {:?}", c)))
+            .collect::<Result<_, _>>()?;
+        if cards.len() != 5 {
+            return Err(format!("expected 5 cards, found {}", cards.len()));
+        }
+        Ok(cards)
+    });
+
+    (cards, literal(" "), uint()).map(|(cards, _, bid)| Hand {
+        kind: HandKind::new(cards),
+        bid,
+    })
+}
+
+fn total_winnings(input: &str, jokers: bool) -> Result<u32> {
+    let mut hands: Vec<Hand> = combinator::parse(input, lines(hand(jokers)))?;
+
+    hands.sort_by_key(|h| h.kind.clone());
+
+    Ok(hands
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (i, hand)| acc + hand.bid * (i + 1) as u32))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        total_winnings(input, false)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        total_winnings(input, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 7, sample: [6440, 5905], real: [250370104, 251735672]);
+}
@@ -0,0 +1,142 @@
+/*
+NOTE: This problem has many different sample inputs and all of them don't work for both parts, so each part
+has its own sample input
+*/
+
+use std::{collections::HashMap, fmt::Display};
+
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    combinator::{self, cells, lines, literal, Failure, Parser},
+    error::SolveError,
+    math::lcm,
+    solution::Solution,
+};
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Left,
+    Right,
+}
+impl Direction {
+    fn from_char(input: char) -> Option<Self> {
+        use Direction::*;
+
+        match input {
+            'L' => Some(Left),
+            'R' => Some(Right),
+            _ => None,
+        }
+    }
+}
+
+type Loc<'a> = &'a str;
+type Map<'a> = HashMap<Loc<'a>, (Loc<'a>, Loc<'a>)>;
+
+const START_NODE: Loc = "AAA";
+const END_NODE: Loc = "ZZZ";
+/// The number of steps from `start` to the first node for which `is_end` holds.
+fn count_steps(
+    directions: &[Direction],
+    map: &Map,
+    start: Loc,
+    is_end: impl Fn(Loc) -> bool,
+) -> Result<u64> {
+    // Once every node has been visited at every point of the directions, the walk is going round
+    // in circles and will never get to the end
+    let limit = directions.len() * map.len();
+
+    let mut curr_node = start;
+    for num_steps in 1..=limit {
+        let (left, right) = map
+            .get(curr_node)
+            .ok_or_else(|| anyhow!("there is no node {}", curr_node))?;
+        curr_node = match directions[(num_steps - 1) % directions.len()] {
+            Direction::Left => left,
+            Direction::Right => right,
+        };
+
+        if is_end(curr_node) {
+            return Ok(num_steps as u64);
+        }
+    }
+
+    Err(SolveError::IterationLimit(limit).into())
+}
+
+fn part1(directions: &[Direction], map: &Map) -> Result<u64> {
+    count_steps(directions, map, START_NODE, |node| node == END_NODE)
+}
+
+fn part2(directions: &[Direction], map: &Map) -> Result<u64> {
+    map.keys()
+        .filter(|node| node.ends_with("A"))
+        .try_fold(1, |steps, node| {
+            let node_steps = count_steps(directions, map, node, |node| node.ends_with("Z"))?;
+            lcm(steps, node_steps)
+                .ok_or_else(|| anyhow!("the number of steps does not fit in a u64"))
+        })
+}
+
+/// The name of a node, which is made up of letters and digits.
+fn node<'a>() -> impl Parser<'a, Loc<'a>> {
+    |input: &'a str| {
+        let end = input
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(input.len());
+        if end == 0 {
+            return Err(Failure {
+                at: input,
+                message: "expected a node".into(),
+            });
+        }
+        Ok(input.split_at(end))
+    }
+}
+
+fn parse_network(input: &str) -> Result<(Vec<Direction>, Map<'_>)> {
+    let connections = (
+        node(),
+        literal(" = ("),
+        node(),
+        literal(", "),
+        node(),
+        literal(")"),
+    )
+        .map(|(loc, _, left, _, right, _)| (loc, (left, right)));
+    let (directions, _, map) = combinator::parse(
+        input,
+        (
+            cells(Direction::from_char, "'L' or 'R'"),
+            literal("\n\n"),
+            lines(connections),
+        ),
+    )?;
+
+    Ok((directions, map.into_iter().collect()))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        let (directions, map) = parse_network(input)?;
+        part1(&directions, &map)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        let (directions, map) = parse_network(input)?;
+        part2(&directions, &map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 8, sample: [2, 6], real: [21409, 21165830176709]);
+}
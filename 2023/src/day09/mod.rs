@@ -0,0 +1,58 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{parse, solution::Solution};
+
+fn extrapolate_ahead(nums: &[i32]) -> i32 {
+    if nums.iter().all(|n| *n == 0) {
+        return 0;
+    }
+
+    let diffs: Vec<i32> = nums.windows(2).map(|w| w[1] - w[0]).collect();
+
+    nums[nums.len() - 1] + extrapolate_ahead(&diffs)
+}
+
+fn extrapolate_behind(nums: &[i32]) -> i32 {
+    if nums.iter().all(|n| *n == 0) {
+        return 0;
+    }
+
+    let diffs: Vec<i32> = nums.windows(2).map(|w| w[1] - w[0]).collect();
+
+    nums[0] - extrapolate_behind(&diffs)
+}
+
+fn part1(readings: &[Vec<i32>]) -> i32 {
+    readings.iter().map(|r| extrapolate_ahead(r)).sum()
+}
+
+fn part2(readings: &[Vec<i32>]) -> i32 {
+    readings.iter().map(|r| extrapolate_behind(r)).sum()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| parse::integers(line).separated_by(" ").collect())
+            .collect()
+    }
+
+    fn part1(readings: &Self::Input) -> Result<impl Display> {
+        Ok(part1(readings))
+    }
+
+    fn part2(readings: &Self::Input) -> Result<impl Display> {
+        Ok(part2(readings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 9, sample: [114, 2], real: [1681758908, 803]);
+}
@@ -0,0 +1,165 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, ensure, Result};
+use aoc_common::{
+    combinator::{self, cells, lines},
+    error::SolveError,
+    grid::{Direction, Point},
+    region::cells_inside,
+    solution::Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PipeTypes {
+    Minus,
+    Pipe,
+    F,
+    J,
+    Seven,
+    L,
+    Ground,
+    Start,
+}
+
+#[derive(Debug)]
+pub struct Pipe {
+    x: usize,
+    y: usize,
+    typ: PipeTypes,
+}
+
+impl PipeTypes {
+    fn from_char(typ: char) -> Option<Self> {
+        use PipeTypes::*;
+        match typ {
+            '-' => Some(Minus),
+            '|' => Some(Pipe),
+            'F' => Some(F),
+            'J' => Some(J),
+            '7' => Some(Seven),
+            'L' => Some(L),
+            '.' => Some(Ground),
+            'S' => Some(Start),
+            _ => None,
+        }
+    }
+}
+
+fn find_loop(
+    map: &[Vec<Pipe>],
+    start_pipe: &Pipe,
+    initial_dir: Direction,
+    dimensions: &(usize, usize),
+) -> Option<Vec<(usize, usize)>> {
+    let mut curr_dir = initial_dir;
+    let mut loop_pipes = vec![];
+    let mut pos = Point::new(start_pipe.x, start_pipe.y);
+
+    loop {
+        pos = pos.step(curr_dir, dimensions.0, dimensions.1)?;
+
+        let next_pipe = &map[pos.y][pos.x];
+        match (next_pipe.typ, curr_dir) {
+            (PipeTypes::Minus, Direction::Right) => curr_dir = Direction::Right,
+            (PipeTypes::Minus, Direction::Left) => curr_dir = Direction::Left,
+            (PipeTypes::Pipe, Direction::Up) => curr_dir = Direction::Up,
+            (PipeTypes::Pipe, Direction::Down) => curr_dir = Direction::Down,
+            (PipeTypes::F, Direction::Up) => curr_dir = Direction::Right,
+            (PipeTypes::F, Direction::Left) => curr_dir = Direction::Down,
+            (PipeTypes::J, Direction::Down) => curr_dir = Direction::Left,
+            (PipeTypes::J, Direction::Right) => curr_dir = Direction::Up,
+            (PipeTypes::Seven, Direction::Up) => curr_dir = Direction::Left,
+            (PipeTypes::Seven, Direction::Right) => curr_dir = Direction::Down,
+            (PipeTypes::L, Direction::Down) => curr_dir = Direction::Right,
+            (PipeTypes::L, Direction::Left) => curr_dir = Direction::Up,
+            (PipeTypes::Ground, _) => return None,
+            // NOTE: We subtract 1 here as we do not want the `PipeTypes::Start` position to be counted in the number of steps
+            (PipeTypes::Start, _) => return Some(loop_pipes),
+            _ => return None,
+        };
+
+        loop_pipes.push((pos.x, pos.y));
+    }
+}
+
+fn no_loop() -> SolveError {
+    SolveError::no_solution("the start pipe is not part of a loop")
+}
+
+fn part1(map: &[Vec<Pipe>], start_pipe: &Pipe, dimensions: &(usize, usize)) -> Result<u32> {
+    let ans = Direction::ALL
+        .into_iter()
+        .filter_map(|dir| find_loop(map, start_pipe, dir, dimensions))
+        .map(|l| l.len())
+        .max()
+        .ok_or_else(no_loop)?;
+
+    Ok(ans.div_ceil(2) as u32)
+}
+
+fn part2(map: &[Vec<Pipe>], start_pipe: &Pipe, dimensions: &(usize, usize)) -> Result<u32> {
+    let mut loop_pipes_coord: Vec<(usize, usize)> = Direction::ALL
+        .into_iter()
+        .find_map(|dir| find_loop(map, start_pipe, dir, dimensions))
+        .ok_or_else(no_loop)?;
+    // insert start_pipe coordinates into our vec since that is also a part of our loop
+    loop_pipes_coord.push((start_pipe.x, start_pipe.y));
+
+    // The pipes are in the order the loop goes through them, so they make up a polygon
+    let loop_pipes: Vec<Point<i64>> = loop_pipes_coord
+        .into_iter()
+        .map(|(x, y)| Point::new(x as i64, y as i64))
+        .collect();
+    Ok(cells_inside(&loop_pipes) as u32)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    /// The map, the position of the start pipe and the dimensions of the map as `(x, y)`.
+    type Input = (Vec<Vec<Pipe>>, (usize, usize), (usize, usize));
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let types: Vec<Vec<PipeTypes>> =
+            combinator::parse(input, lines(cells(PipeTypes::from_char, "a pipe")))?;
+        ensure!(
+            types.iter().all(|row| row.len() == types[0].len()),
+            "the rows of the map are not all equally long"
+        );
+        let map: Vec<Vec<Pipe>> = types
+            .into_iter()
+            .enumerate()
+            .map(|(y, row)| {
+                row.into_iter()
+                    .enumerate()
+                    .map(|(x, typ)| Pipe { x, y, typ })
+                    .collect()
+            })
+            .collect();
+
+        // (x, y)
+        let dimensions = (map[0].len(), map.len());
+
+        let start_pipe = map
+            .iter()
+            .flatten()
+            .find(|pipe| pipe.typ == PipeTypes::Start)
+            .ok_or_else(|| anyhow!("Missing start pipe"))?;
+        let start = (start_pipe.x, start_pipe.y);
+
+        Ok((map, start, dimensions))
+    }
+
+    fn part1((map, start, dimensions): &Self::Input) -> Result<impl Display> {
+        part1(map, &map[start.1][start.0], dimensions)
+    }
+
+    fn part2((map, start, dimensions): &Self::Input) -> Result<impl Display> {
+        part2(map, &map[start.1][start.0], dimensions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 10, sample: [80, 10], real: [7030, 285]);
+}
@@ -0,0 +1,100 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{
+    combinator::{self, cells, lines},
+    grid::Grid,
+    solution::Solution,
+};
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Point {
+    Space,
+    Galaxy,
+}
+
+impl Point {
+    fn from_char(input: char) -> Option<Self> {
+        match input {
+            '.' => Some(Self::Space),
+            '#' => Some(Self::Galaxy),
+            _ => None,
+        }
+    }
+}
+
+pub type Image = Grid<Point>;
+
+/// The rows without any galaxies in them.
+fn empty_rows(img: &Image) -> Vec<usize> {
+    (0..img.height())
+        .filter(|y| !img.row(*y).contains(&Point::Galaxy))
+        .collect()
+}
+
+/// The distance between two galaxies along one axis, where each empty row or column in between
+/// counts `mul_factor` times.
+fn distance(a: usize, b: usize, empty: &[usize], mul_factor: usize) -> usize {
+    let (min, max) = (a.min(b), a.max(b));
+    let expanded = empty
+        .iter()
+        .filter(|id| (min + 1..max).contains(id))
+        .count();
+    max - min + expanded * (mul_factor - 1)
+}
+
+fn solve(img: &Image, mul_factor: usize) -> usize {
+    let empty_row_ids = empty_rows(img);
+    // The columns of the image are the rows of its transpose
+    let empty_col_ids = empty_rows(&img.transpose());
+
+    let galaxies: Vec<_> = img
+        .iter()
+        .filter(|(_, point)| **point == Point::Galaxy)
+        .map(|(coords, _)| coords)
+        .collect();
+
+    galaxies
+        .iter()
+        .tuple_combinations()
+        .map(|(g1, g2)| {
+            distance(g1.x, g2.x, &empty_col_ids, mul_factor)
+                + distance(g1.y, g2.y, &empty_row_ids, mul_factor)
+        })
+        .sum()
+}
+
+fn part1(img: &Image) -> usize {
+    solve(img, 2)
+}
+
+fn part2(img: &Image) -> usize {
+    solve(img, 1_000_000)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Image;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::from_rows(combinator::parse(
+            input,
+            lines(cells(Point::from_char, "'.' or '#'")),
+        )?)
+    }
+
+    fn part1(img: &Self::Input) -> Result<impl Display> {
+        Ok(part1(img))
+    }
+
+    fn part2(img: &Self::Input) -> Result<impl Display> {
+        Ok(part2(img))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 11, sample: [374, 82000210], real: [9509330, 635832237682]);
+}
@@ -0,0 +1,122 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{
+    combinator::{self, lines, literal, separated, token, uint, Parser},
+    solution::{unsolved, Solution},
+};
+use itertools::{repeat_n, Itertools};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Spring {
+    Damaged,
+    Operational,
+    Unknown,
+}
+
+impl TryFrom<char> for Spring {
+    type Error = String;
+
+    fn try_from(input: char) -> Result<Self, String> {
+        use Spring::*;
+        match input {
+            '#' => Ok(Damaged),
+            '.' => Ok(Operational),
+            '?' => Ok(Unknown),
+            _ => Err(format!("expected '#', '.' or '?', found {:?}", input)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Row {
+    springs: Vec<Spring>,
+    damaged_groups: Vec<u32>,
+}
+
+/// The springs, a space and the sizes of the groups of damaged springs, e.g. `???.### 1,1,3`.
+fn row<'a>() -> impl Parser<'a, Row> {
+    let springs = token().try_map(|springs| springs.chars().map(Spring::try_from).collect());
+
+    (springs, literal(" "), separated(uint(), literal(","))).map(|(springs, _, damaged_groups)| {
+        Row {
+            springs,
+            damaged_groups,
+        }
+    })
+}
+
+impl Row {
+    fn get_num_unknowns(&self) -> usize {
+        self.springs
+            .iter()
+            .filter(|spring| spring == &&Spring::Unknown)
+            .count()
+    }
+
+    fn is_valid(&self, new_seq: &[Spring]) -> bool {
+        let mut groups = Vec::with_capacity(self.damaged_groups.len());
+        for (is_damaged, springs) in &new_seq
+            .iter()
+            .chunk_by(|spring| spring == &&Spring::Damaged)
+        {
+            if is_damaged {
+                groups.push(springs.count() as u32)
+            }
+        }
+
+        groups == self.damaged_groups
+    }
+}
+
+fn solve(rows: &[Row]) -> u32 {
+    rows.iter().fold(0, |acc, row| {
+        let num_valid_replacements = repeat_n(
+            [Spring::Damaged, Spring::Operational],
+            row.get_num_unknowns(),
+        )
+        .multi_cartesian_product()
+        .fold(0, |acc, replacement| {
+            let mut new_seq = row.springs.clone();
+            let mut i = 0;
+            for spring in new_seq.iter_mut() {
+                if spring == &mut Spring::Unknown {
+                    replacement[i].clone_into(spring);
+                    i += 1;
+                }
+            }
+
+            if row.is_valid(&new_seq) {
+                acc + 1
+            } else {
+                acc
+            }
+        });
+
+        acc + num_valid_replacements
+    }) as u32
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Row>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        combinator::parse(input, lines(row()))
+    }
+
+    fn part1(rows: &Self::Input) -> Result<impl Display> {
+        Ok(solve(rows))
+    }
+
+    fn part2(_rows: &Self::Input) -> Result<impl Display> {
+        // Trying every replacement of the unfolded rows does not finish in any reasonable time.
+        unsolved()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 12, sample: [21, _], real: [7402, _]);
+}
@@ -0,0 +1,108 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{
+    combinator::{self, cells, lines, sections, Parser},
+    error::SolveError,
+    grid::Grid,
+    solution::Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum FloorType {
+    Ash,
+    Rock,
+}
+
+impl FloorType {
+    fn from_char(input: char) -> Option<Self> {
+        match input {
+            '#' => Some(Self::Rock),
+            '.' => Some(Self::Ash),
+            _ => None,
+        }
+    }
+}
+
+type Floor = Grid<FloorType>;
+
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    floor: Floor,
+}
+
+fn pattern<'a>() -> impl Parser<'a, Pattern> {
+    lines(cells(FloorType::from_char, "'#' or '.'"))
+        .try_map(Grid::from_rows)
+        .map(|floor| Pattern { floor })
+}
+
+/// The number of cells that differ from their reflection when mirroring `floor` between rows
+/// `id` and `id + 1`.
+fn get_mirror_row_err(floor: &Floor, id: usize) -> usize {
+    (0..=id)
+        .rev()
+        .zip(id + 1..floor.height())
+        .map(|(side1_id, side2_id)| {
+            floor
+                .row(side1_id)
+                .iter()
+                .zip(floor.row(side2_id))
+                .filter(|(a, b)| a != b)
+                .count()
+        })
+        .sum()
+}
+
+fn find_mirror_row(floor: &Floor, err_amt: usize) -> Option<usize> {
+    (0..floor.height() - 1).find(|&i| get_mirror_row_err(floor, i) == err_amt)
+}
+
+impl Pattern {
+    fn find_mirror_col(&self, err_amt: usize) -> Option<usize> {
+        // The columns of the floor are the rows of its transpose
+        find_mirror_row(&self.floor.transpose(), err_amt)
+    }
+
+    fn find_mirror_row(&self, err_amt: usize) -> Option<usize> {
+        find_mirror_row(&self.floor, err_amt)
+    }
+}
+
+fn solve(patterns: &[Pattern], err_amt: usize) -> Result<usize> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            if let Some(idx) = pattern.find_mirror_col(err_amt) {
+                Ok(idx + 1) // Add 1 as the problem assumes that indexes start at 1
+            } else if let Some(idx) = pattern.find_mirror_row(err_amt) {
+                Ok((idx + 1) * 100) // Add 1 as the problem assumes that indexes start at 1
+            } else {
+                Err(SolveError::no_solution("a pattern has no line of reflection").into())
+            }
+        })
+        .sum()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        combinator::parse(input, sections(pattern()))
+    }
+
+    fn part1(patterns: &Self::Input) -> Result<impl Display> {
+        solve(patterns, 0)
+    }
+
+    fn part2(patterns: &Self::Input) -> Result<impl Display> {
+        solve(patterns, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 13, sample: [405, 400], real: [40006, 28627]);
+}
@@ -0,0 +1,103 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{
+    combinator::{self, cells, lines},
+    cycle,
+    grid::{Direction, Grid},
+    solution::Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum Rock {
+    Rounded,
+    Cube,
+    Empty,
+}
+
+impl Rock {
+    fn from_char(input: char) -> Option<Self> {
+        match input {
+            'O' => Some(Self::Rounded),
+            '#' => Some(Self::Cube),
+            '.' => Some(Self::Empty),
+            _ => None,
+        }
+    }
+}
+
+pub type Panel = Grid<Rock>;
+
+fn tilt(panel: &mut Panel, direction: Direction) {
+    panel.slide(
+        direction,
+        |rock| *rock == Rock::Rounded,
+        |rock| *rock == Rock::Empty,
+    );
+}
+
+fn spin_cycle(panel: &mut Panel) {
+    for direction in [
+        Direction::Up,
+        Direction::Left,
+        Direction::Down,
+        Direction::Right,
+    ] {
+        tilt(panel, direction);
+    }
+}
+
+fn load(panel: &Panel) -> usize {
+    panel
+        .iter()
+        .filter(|(_, rock)| **rock == Rock::Rounded)
+        .map(|(point, _)| panel.height() - point.y)
+        .sum()
+}
+
+fn part1(panel: &Panel) -> usize {
+    let mut panel = panel.clone();
+    tilt(&mut panel, Direction::Up);
+
+    load(&panel)
+}
+
+fn part2(panel: &Panel) -> usize {
+    let panel = cycle::nth(
+        panel.clone(),
+        |panel| {
+            let mut panel = panel.clone();
+            spin_cycle(&mut panel);
+            panel
+        },
+        1_000_000_000,
+    );
+
+    load(&panel)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Panel;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::from_rows(combinator::parse(
+            input,
+            lines(cells(Rock::from_char, "'O', '#' or '.'")),
+        )?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 14, sample: [136, 64], real: [108918, 100310]);
+}
@@ -0,0 +1,94 @@
+use std::{collections::HashMap, fmt::Display};
+
+use anyhow::Result;
+use aoc_common::{
+    combinator::{self, literal, separated, uint, word, Parser},
+    solution::Solution,
+};
+use itertools::Itertools;
+
+fn hash(seq: &str) -> u32 {
+    seq.chars().fold(0, |acc, c| ((acc + c as u32) * 17) % 256)
+}
+
+fn part1(input: &str) -> u32 {
+    let sequences = input.split(",");
+
+    sequences.map(hash).sum()
+}
+
+/// A label followed by `=` and the focal length of the lens to put in, or by `-` to take the lens
+/// out, which is `None`.
+fn step<'a>() -> impl Parser<'a, (&'a str, Option<u32>)> {
+    let operation = |input: &'a str| match input.strip_prefix('-') {
+        Some(rest) => Ok((None, rest)),
+        None => (literal("="), uint())
+            .map(|(_, focal_len)| Some(focal_len))
+            .parse_from(input),
+    };
+
+    (word(), operation)
+}
+
+fn part2(input: &str) -> Result<u32> {
+    let mut boxes: HashMap<u32, Vec<Option<(&str, u32)>>> = HashMap::with_capacity(256);
+    let sequences = combinator::parse(input, separated(step(), literal(",")))?;
+
+    for (pos, focal_len) in sequences {
+        if let Some(focal_len) = focal_len {
+            let b = boxes.entry(hash(pos)).or_insert(vec![]);
+
+            let found = b
+                .iter()
+                .find_position(|elem| matches!(elem, Some((p, _)) if *p == pos));
+            if let Some((id, _)) = found {
+                b[id] = Some((pos, focal_len));
+            } else {
+                b.push(Some((pos, focal_len)));
+            }
+        } else {
+            let b = boxes.entry(hash(pos)).or_insert(vec![]);
+            let found = b
+                .iter()
+                .find_position(|elem| matches!(elem, Some((p, _)) if *p == pos));
+            if let Some((id, _)) = found {
+                b[id] = None;
+            }
+        }
+    }
+
+    Ok(boxes
+        .iter()
+        .map(|(id, b)| {
+            (id + 1)
+                * b.iter()
+                    .flatten()
+                    .enumerate()
+                    .map(|(id, (_, focal_len))| (id as u32 + 1) * (*focal_len))
+                    .sum::<u32>()
+        })
+        .sum())
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim_end().to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 15, sample: [1320, 145], real: [509152, 244403]);
+}
@@ -0,0 +1,167 @@
+use std::{collections::HashSet, fmt::Display};
+
+use anyhow::{ensure, Result};
+use aoc_common::{
+    combinator::{self, cells, lines},
+    grid::{Direction, Point},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Tile {
+    Empty,
+    RightMirror,
+    LeftMirror,
+    VerticalSplitter,
+    HorizontalSplitter,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Tile> {
+        match c {
+            '.' => Some(Tile::Empty),
+            '/' => Some(Tile::RightMirror),
+            '\\' => Some(Tile::LeftMirror),
+            '|' => Some(Tile::VerticalSplitter),
+            '-' => Some(Tile::HorizontalSplitter),
+            _ => None,
+        }
+    }
+}
+
+pub type Layout = Vec<Vec<Tile>>;
+
+const START_POS: (usize, usize) = (0, 0);
+
+fn step(layout: &Layout, pos: (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+    Point::new(pos.0, pos.1)
+        .step(direction, layout[0].len(), layout.len())
+        .map(|p| (p.x, p.y))
+}
+
+fn find_next_pos(
+    layout: &Layout,
+    curr_pos: (usize, usize),
+    direction: Direction,
+    visited: &mut HashSet<((usize, usize), Direction)>,
+) {
+    let mut curr_pos = curr_pos;
+    let mut curr_dir = direction;
+    let mut curr_tile = layout[curr_pos.1][curr_pos.0];
+
+    loop {
+        visited.insert((curr_pos, curr_dir));
+
+        curr_dir = match curr_tile {
+            Tile::Empty => curr_dir,
+            Tile::RightMirror => match curr_dir {
+                Direction::Up => Direction::Right,
+                Direction::Down => Direction::Left,
+                Direction::Right => Direction::Up,
+                Direction::Left => Direction::Down,
+            },
+            Tile::LeftMirror => match curr_dir {
+                Direction::Up => Direction::Left,
+                Direction::Down => Direction::Right,
+                Direction::Right => Direction::Down,
+                Direction::Left => Direction::Up,
+            },
+            Tile::VerticalSplitter => match curr_dir {
+                Direction::Up | Direction::Down => curr_dir,
+                Direction::Right | Direction::Left => {
+                    for dir in [Direction::Up, Direction::Down] {
+                        if let Some(pos) = step(layout, curr_pos, dir) {
+                            find_next_pos(layout, pos, dir, visited);
+                        }
+                    }
+                    return;
+                }
+            },
+            Tile::HorizontalSplitter => match curr_dir {
+                Direction::Right | Direction::Left => curr_dir,
+                Direction::Up | Direction::Down => {
+                    for dir in [Direction::Right, Direction::Left] {
+                        if let Some(pos) = step(layout, curr_pos, dir) {
+                            find_next_pos(layout, pos, dir, visited);
+                        }
+                    }
+                    return;
+                }
+            },
+        };
+
+        let Some(next_pos) = step(layout, curr_pos, curr_dir) else {
+            return;
+        };
+        curr_pos = next_pos;
+        curr_tile = layout[curr_pos.1][curr_pos.0];
+
+        // Make sure we don't repeat any loops
+        if visited.contains(&(curr_pos, curr_dir)) {
+            return;
+        }
+    }
+}
+
+fn solve(layout: &Layout, start_pos: (usize, usize), start_dir: Direction) -> usize {
+    let mut energized_tiles: HashSet<((usize, usize), Direction)> = HashSet::new();
+
+    find_next_pos(layout, start_pos, start_dir, &mut energized_tiles);
+
+    let mut energized_locs = HashSet::new();
+    for (loc, _) in energized_tiles {
+        energized_locs.insert(loc);
+    }
+
+    energized_locs.len()
+}
+
+fn part1(layout: &Layout) -> usize {
+    solve(layout, START_POS, Direction::Right)
+}
+
+fn part2(layout: &Layout) -> usize {
+    let mut num_energized = vec![];
+    for i in 0..layout.len() {
+        num_energized.push(solve(layout, (0, i), Direction::Right));
+        num_energized.push(solve(layout, (layout[0].len() - 1, i), Direction::Left));
+    }
+    for i in 0..layout[0].len() {
+        num_energized.push(solve(layout, (i, 0), Direction::Down));
+        num_energized.push(solve(layout, (i, layout.len() - 1), Direction::Up));
+    }
+
+    *num_energized.iter().max().unwrap()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Layout;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let layout: Layout = combinator::parse(
+            input,
+            lines(cells(Tile::from_char, "a mirror, splitter or empty space")),
+        )?;
+        ensure!(
+            layout.iter().all(|row| row.len() == layout[0].len()),
+            "the rows of the layout are not all equally long"
+        );
+
+        Ok(layout)
+    }
+
+    fn part1(layout: &Self::Input) -> Result<impl Display> {
+        Ok(part1(layout))
+    }
+
+    fn part2(layout: &Self::Input) -> Result<impl Display> {
+        Ok(part2(layout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 16, sample: [46, 51], real: [7562, 7793]);
+}
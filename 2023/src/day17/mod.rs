@@ -0,0 +1,77 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{
+    combinator::{self, cells, lines},
+    debug,
+    error::SolveError,
+    grid::{Direction, Grid, Point},
+    search::dijkstra,
+    solution::Solution,
+};
+
+pub type CityMap = Grid<u32>;
+
+/// Where the crucible is, which way it was last moving and how many blocks it has moved that way.
+type State = (Point, Option<Direction>, u32);
+
+/// The least heat lost on the way from the top left to the bottom right, when the crucible has to
+/// move at least `min_run` blocks before turning or stopping and at most `max_run` blocks in a row.
+fn min_heat_loss(city_map: &CityMap, min_run: u32, max_run: u32) -> Option<u32> {
+    let end = Point::new(city_map.width() - 1, city_map.height() - 1);
+
+    let (path, heat_loss) = dijkstra(
+        (Point::new(0, 0), None, 0),
+        |&(point, direction, run): &State| {
+            Direction::ALL.into_iter().filter_map(move |next| {
+                let run = match direction {
+                    None => 1,
+                    Some(direction) if next == direction.opposite() => return None,
+                    Some(direction) if next == direction && run < max_run => run + 1,
+                    Some(direction) if next != direction && run >= min_run => 1,
+                    Some(_) => return None,
+                };
+                let next_point = city_map.step(point, next)?;
+                Some(((next_point, Some(next), run), city_map[next_point]))
+            })
+        },
+        |&(point, _, run)| point == end && run >= min_run,
+    )?;
+
+    if debug::enabled() {
+        let render = city_map
+            .render(|heat| char::from_digit(*heat, 10).unwrap())
+            .highlight(path.iter().map(|(point, _, _)| *point), '#');
+        eprint!("{}", render);
+    }
+
+    Some(heat_loss)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = CityMap;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::from_rows(combinator::parse(
+            input,
+            lines(cells(|c| c.to_digit(10), "a digit")),
+        )?)
+    }
+
+    fn part1(city_map: &Self::Input) -> Result<impl Display> {
+        min_heat_loss(city_map, 1, 3)
+            .ok_or_else(|| SolveError::no_solution("the crucible can not reach the end").into())
+    }
+
+    fn part2(city_map: &Self::Input) -> Result<impl Display> {
+        min_heat_loss(city_map, 4, 10)
+            .ok_or_else(|| SolveError::no_solution("the crucible can not reach the end").into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 17, sample: [102, 94], real: [1110, 1294]);
+}
@@ -0,0 +1,123 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, bail, Result};
+use aoc_common::{
+    combinator::{self, cells, lines},
+    grid::{Direction, Grid, Point},
+    search::bfs_distances,
+    solution::Solution,
+    unbounded::TiledGrid,
+};
+
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub enum Node {
+    Start,
+    Rock,
+    Plot,
+}
+
+impl Node {
+    fn from_char(input: char) -> Option<Self> {
+        match input {
+            'S' => Some(Node::Start),
+            '#' => Some(Node::Rock),
+            '.' => Some(Node::Plot),
+            _ => None,
+        }
+    }
+}
+
+pub type Map = TiledGrid<Node>;
+
+/// The number of plots the elf could end up on after exactly `steps` steps from `start`, where
+/// `is_open` tells which points can be stepped on.
+fn reachable_plots(start: Point<i64>, steps: usize, is_open: impl Fn(Point<i64>) -> bool) -> usize {
+    // Nothing further than `steps` away as the crow flies can be reached, which keeps the search
+    // finite on a map that repeats forever
+    let distances = bfs_distances(start, |point: &Point<i64>| {
+        Direction::ALL
+            .map(|direction| *point + direction.offset().into())
+            .into_iter()
+            .filter(|next| is_open(*next) && next.manhattan_distance(&start) as usize <= steps)
+            .collect::<Vec<_>>()
+    });
+
+    // The elf can waste steps by going back and forth, so every plot that is reached with the
+    // same parity as `steps` can be the last one
+    distances
+        .values()
+        .filter(|distance| **distance <= steps && **distance % 2 == steps % 2)
+        .count()
+}
+
+fn part1(map: &Map, start: Point<i64>) -> usize {
+    reachable_plots(start, 64, |point| {
+        Point::<usize>::try_from(point)
+            .ok()
+            .and_then(|point| map.tile().get(&point))
+            .is_some_and(|node| *node != Node::Rock)
+    })
+}
+
+const STEPS: usize = 26501365;
+
+/// The map repeats forever, but the row and column of the start are free of rocks, so the
+/// reachable area grows by whole copies of the map every `width` steps once it hits the edge. The
+/// number of plots is therefore quadratic in the number of copies crossed, and can be
+/// extrapolated from the first three.
+fn part2(map: &Map, start: Point<i64>) -> Result<usize> {
+    let size = map.tile().width();
+    let half = size / 2;
+    if map.tile().height() != size || start != Point::new(half as i64, half as i64) {
+        bail!("the map is not a square with the start in its centre");
+    }
+    let Some(rest) = STEPS
+        .checked_sub(half)
+        .filter(|rest| rest.is_multiple_of(size))
+    else {
+        bail!("the steps do not cross a whole number of copies of the map");
+    };
+
+    let [a, b, c] = [0, 1, 2].map(|copies| {
+        reachable_plots(start, half + copies * size, |point| {
+            map[point] != Node::Rock
+        })
+    });
+    let n = rest / size;
+
+    Ok(a + n * (b - a) + n * (n - 1) / 2 * (c + a - 2 * b))
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    /// The map and the starting position.
+    type Input = (Map, Point<i64>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let grid = Grid::from_rows(combinator::parse(
+            input,
+            lines(cells(Node::from_char, "'S', '#' or '.'")),
+        )?)?;
+        let start = grid
+            .iter()
+            .find(|(_, node)| **node == Node::Start)
+            .map(|(point, _)| point)
+            .ok_or_else(|| anyhow!("Missing start position"))?;
+
+        Ok((TiledGrid::new(grid)?, start.try_into()?))
+    }
+
+    fn part1((map, start): &Self::Input) -> Result<impl Display> {
+        Ok(part1(map, *start))
+    }
+
+    fn part2((map, start): &Self::Input) -> Result<impl Display> {
+        part2(map, *start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 21, sample: [42, _], real: [3746, 623540829615589]);
+}
@@ -0,0 +1,96 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::Result;
+use aoc_common::{
+    parse, scan,
+    solution::{unsolved, Solution},
+};
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy)]
+pub struct HailStone {
+    pos: (f64, f64, f64),
+    vel: (f64, f64, f64),
+}
+
+impl FromStr for HailStone {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (x, y, z, vx, vy, vz) =
+            scan!(input, "{}, {}, {} @ {}, {}, {}" => f64, f64, f64, f64, f64, f64)?;
+        Ok(Self {
+            pos: (x, y, z),
+            vel: (vx, vy, vz),
+        })
+    }
+}
+
+impl HailStone {
+    fn get_pos_at_time(&self, time: f64) -> (f64, f64, f64) {
+        (
+            self.pos.0 + self.vel.0 * time,
+            self.pos.1 + self.vel.1 * time,
+            self.pos.2 + self.vel.2 * time,
+        )
+    }
+
+    /// The times at which `self` and `other` pass through the point where their paths cross in the
+    /// xy plane, found by solving `self.pos + self.vel * t1 = other.pos + other.vel * t2` for
+    /// `(t1, t2)` with Cramer's rule.
+    fn intersects_at_xy(&self, other: &HailStone) -> Option<(f64, f64)> {
+        let (a, b) = (self.vel.0, -other.vel.0);
+        let (c, d) = (self.vel.1, -other.vel.1);
+        let (e, f) = (other.pos.0 - self.pos.0, other.pos.1 - self.pos.1);
+
+        let det = a * d - b * c;
+        if det == 0.0 {
+            // The paths are parallel
+            return None;
+        }
+
+        Some(((e * d - b * f) / det, (a * f - e * c) / det))
+    }
+}
+
+fn part1(hailstones: &[HailStone]) -> usize {
+    let bounds = 200000000000000f64..=400000000000000f64;
+
+    hailstones
+        .iter()
+        .tuple_combinations()
+        .filter(|(ha, hb)| {
+            let res = ha.intersects_at_xy(hb);
+            if res.is_none() {
+                return false;
+            }
+            let res = res.unwrap();
+            let pos = ha.get_pos_at_time(res.0);
+            res.0 > 0.0 && res.1 > 0.0 && bounds.contains(&pos.0) && bounds.contains(&pos.1)
+        })
+        .count()
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<HailStone>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<impl Display> {
+        unsolved()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // the test area is hard-coded for the real input, so there is no meaningful sample answer
+    aoc_common::day_tests!(crate::YEAR, 24, real: [13892, _]);
+}
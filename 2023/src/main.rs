@@ -1,3 +1,5 @@
-fn main() {
-    println!("Run the binary for a specific day with `cargo run --bin dayXX`!");
+use anyhow::Result;
+
+fn main() -> Result<()> {
+    aoc_common::solution::main(&aoc2023::YEAR)
 }
@@ -0,0 +1,63 @@
+use std::{collections::HashMap, fmt::Display};
+
+use anyhow::{anyhow, Result};
+use aoc_common::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Vec<u32>, Vec<u32>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lists: Vec<(u32, u32)> = input
+            .lines()
+            .map(|x| {
+                let (x, y) = x
+                    .split_once("   ")
+                    .ok_or_else(|| anyhow!("Invalid line: {}", x))?;
+                Ok((x.parse::<u32>()?, y.parse::<u32>()?))
+            })
+            .collect::<Result<_>>()?;
+        let list1: Vec<u32> = lists.iter().map(|(x, _)| *x).sorted().collect();
+        let list2: Vec<u32> = lists.iter().map(|(_, y)| *y).sorted().collect();
+
+        Ok((list1, list2))
+    }
+
+    fn part1((list1, list2): &Self::Input) -> Result<impl Display> {
+        let mut total_distance = 0;
+        for (x, y) in list1.iter().zip(list2.iter()) {
+            total_distance += x.abs_diff(*y);
+        }
+
+        Ok(total_distance)
+    }
+
+    fn part2((list1, list2): &Self::Input) -> Result<impl Display> {
+        let mut list1_counter: HashMap<u32, u32> = HashMap::new();
+        let mut list2_counter: HashMap<u32, u32> = HashMap::new();
+
+        for (x, y) in list1.iter().zip(list2.iter()) {
+            let count1 = list1_counter.entry(*x).or_insert(0);
+            *count1 += 1;
+
+            let count2 = list2_counter.entry(*y).or_insert(0);
+            *count2 += 1;
+        }
+
+        let mut similarity_score = 0;
+        for (num, val) in list1_counter.into_iter() {
+            if let Some(y) = list2_counter.get(&num) {
+                similarity_score += val * num * y;
+            }
+        }
+
+        Ok(similarity_score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 1, sample: [11, 31], real: [2166959, 23741109]);
+}
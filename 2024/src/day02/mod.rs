@@ -0,0 +1,102 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::Result;
+use aoc_common::{parse, solution::Solution};
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    levels: Vec<i32>,
+}
+
+impl FromStr for Report {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Report {
+            levels: parse::integers(s)
+                .separated_by(" ")
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+fn count_safe(reports: &[Report]) -> usize {
+    reports
+        .iter()
+        .filter(|r| {
+            let mut iter = r.levels.iter();
+            // a report with a single level has nothing that could be unsafe about it
+            let (Some(first), Some(mut prev)) = (iter.next(), iter.next()) else {
+                return true;
+            };
+
+            if !(1..=3).contains(&first.abs_diff(*prev)) {
+                return false;
+            }
+
+            let increasing = first < prev;
+
+            for level in iter {
+                if (increasing && level < prev) || (!increasing && level > prev) {
+                    return false;
+                }
+
+                if !(1..=3).contains(&level.abs_diff(*prev)) {
+                    return false;
+                }
+
+                prev = level;
+            }
+
+            true
+        })
+        .count()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Report>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input)
+    }
+
+    fn part1(reports: &Self::Input) -> Result<impl Display> {
+        Ok(count_safe(reports))
+    }
+
+    fn part2(reports: &Self::Input) -> Result<impl Display> {
+        let mut ans = 0;
+
+        for r in reports {
+            let mut new_reports = Vec::new();
+            new_reports.push(r.clone());
+
+            let mut perms = Vec::new();
+            for i in 0..r.levels.len() {
+                let mut perm = Vec::new();
+                for j in 0..r.levels.len() {
+                    if i != j {
+                        perm.push(r.levels[j]);
+                    }
+                }
+                perms.push(perm);
+            }
+
+            for perm in perms {
+                new_reports.push(Report { levels: perm });
+            }
+            if count_safe(&new_reports) > 0 {
+                ans += 1;
+            }
+        }
+
+        Ok(ans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 2, sample: [2, 4], real: [463, 514]);
+}
@@ -0,0 +1,142 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, Result};
+use aoc_common::solution::Solution;
+
+#[derive(Debug)]
+struct Instruction {
+    op: String,
+    args: Option<String>,
+}
+
+impl Instruction {
+    fn parse_args(&self) -> Result<(i32, i32)> {
+        match &self.args {
+            Some(args) => {
+                let args: Vec<&str> = args.split(",").collect();
+                if args.len() != 2 {
+                    return Err(anyhow!("Invalid arguments"));
+                }
+
+                let a = args[0].parse::<i32>()?;
+                let b = args[1].parse::<i32>()?;
+                if (0..=999).contains(&a) && (0..=999).contains(&b) {
+                    Ok((a, b))
+                } else {
+                    Err(anyhow!("Invalid arguments"))
+                }
+            }
+            None => Err(anyhow!("Invalid arguments")),
+        }
+    }
+
+    fn execute(&self) -> i32 {
+        match &self.op[..] {
+            "mul" => {
+                if let Ok((a, b)) = self.parse_args() {
+                    a * b
+                } else {
+                    0
+                }
+            }
+            _ => 0,
+        }
+    }
+}
+
+fn part1(input: &str) -> i32 {
+    let mut ans = 0;
+
+    let mut i = 0;
+    while i < input.len() - 4 {
+        if &input[i..i + 4] != "mul(" {
+            i += 1;
+            continue;
+        }
+        i += 4;
+        let mut j = i;
+        while j < input.len() {
+            if &input[j..j + 1] == ")" {
+                break;
+            }
+            j += 1;
+        }
+        let instruction = Instruction {
+            op: "mul".to_string(),
+            args: Some(input[i..j].to_string()),
+        };
+
+        ans += instruction.execute();
+    }
+
+    ans
+}
+
+fn part2(input: &str) -> i32 {
+    let mut ans = 0;
+
+    let mut i = 0;
+    let mut instruction_enabled = true;
+    while i < input.len() - 4 {
+        if &input[i..i + 4] == "do()" {
+            i += 4;
+            instruction_enabled = true;
+            continue;
+        }
+
+        if i <= input.len() - 7 && &input[i..i + 7] == "don't()" {
+            i += 7;
+            instruction_enabled = false;
+            continue;
+        }
+
+        if !instruction_enabled {
+            i += 1;
+            continue;
+        }
+
+        if &input[i..i + 4] != "mul(" {
+            i += 1;
+            continue;
+        }
+        i += 4;
+        let mut j = i;
+        while j < input.len() {
+            if &input[j..j + 1] == ")" {
+                break;
+            }
+            j += 1;
+        }
+        let instruction = Instruction {
+            op: "mul".to_string(),
+            args: Some(input[i..j].to_string()),
+        };
+
+        ans += instruction.execute();
+    }
+
+    ans
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 3, sample: [161, 48], real: [189527826, 63013756]);
+}
@@ -0,0 +1,129 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::solution::Solution;
+
+fn part1(input: &str) -> i32 {
+    let grid: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+
+    let mut ans = 0;
+
+    // Horizontal
+    for row in grid.iter() {
+        for j in 0..row.len() - 3 {
+            if (row[j] == 'X' && row[j + 1] == 'M' && row[j + 2] == 'A' && row[j + 3] == 'S')
+                || (row[j] == 'S' && row[j + 1] == 'A' && row[j + 2] == 'M' && row[j + 3] == 'X')
+            {
+                ans += 1;
+            }
+        }
+    }
+
+    // Vertical
+    for i in 0..grid.len() - 3 {
+        for (j, cell) in grid[i].iter().enumerate() {
+            if (*cell == 'X'
+                && grid[i + 1][j] == 'M'
+                && grid[i + 2][j] == 'A'
+                && grid[i + 3][j] == 'S')
+                || (*cell == 'S'
+                    && grid[i + 1][j] == 'A'
+                    && grid[i + 2][j] == 'M'
+                    && grid[i + 3][j] == 'X')
+            {
+                ans += 1;
+            }
+        }
+    }
+
+    // Diagonal
+    for i in 0..grid.len() - 3 {
+        for j in 0..grid[i].len() - 3 {
+            if (grid[i][j] == 'X'
+                && grid[i + 1][j + 1] == 'M'
+                && grid[i + 2][j + 2] == 'A'
+                && grid[i + 3][j + 3] == 'S')
+                || (grid[i][j] == 'S'
+                    && grid[i + 1][j + 1] == 'A'
+                    && grid[i + 2][j + 2] == 'M'
+                    && grid[i + 3][j + 3] == 'X')
+            {
+                ans += 1;
+            }
+        }
+    }
+
+    // Anti-diagonal
+    for i in 0..grid.len() - 3 {
+        for j in 3..grid[i].len() {
+            if (grid[i][j] == 'X'
+                && grid[i + 1][j - 1] == 'M'
+                && grid[i + 2][j - 2] == 'A'
+                && grid[i + 3][j - 3] == 'S')
+                || (grid[i][j] == 'S'
+                    && grid[i + 1][j - 1] == 'A'
+                    && grid[i + 2][j - 2] == 'M'
+                    && grid[i + 3][j - 3] == 'X')
+            {
+                ans += 1;
+            }
+        }
+    }
+
+    ans
+}
+
+fn part2(input: &str) -> i32 {
+    let grid = input
+        .lines()
+        .map(|l| l.chars().collect())
+        .collect::<Vec<Vec<char>>>();
+
+    let mut ans = 0;
+
+    // Horizontal
+    for i in 1..grid.len() - 1 {
+        for j in 1..grid[i].len() - 1 {
+            if ((grid[i - 1][j - 1] == 'M' && grid[i - 1][j + 1] == 'M')
+                && grid[i][j] == 'A'
+                && (grid[i + 1][j - 1] == 'S' && grid[i + 1][j + 1] == 'S'))
+                || ((grid[i - 1][j - 1] == 'S' && grid[i - 1][j + 1] == 'S')
+                    && grid[i][j] == 'A'
+                    && (grid[i + 1][j - 1] == 'M' && grid[i + 1][j + 1] == 'M'))
+                || ((grid[i - 1][j - 1] == 'S' && grid[i - 1][j + 1] == 'M')
+                    && grid[i][j] == 'A'
+                    && (grid[i + 1][j - 1] == 'S' && grid[i + 1][j + 1] == 'M'))
+                || ((grid[i - 1][j - 1] == 'M' && grid[i - 1][j + 1] == 'S')
+                    && grid[i][j] == 'A'
+                    && (grid[i + 1][j - 1] == 'M' && grid[i + 1][j + 1] == 'S'))
+            {
+                ans += 1;
+            }
+        }
+    }
+
+    ans
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 4, sample: [18, 9], real: [2358, 1737]);
+}
@@ -0,0 +1,100 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{
+    combinator::{self, lines, literal, separated, uint, Parser},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Rule {
+    x: i32,
+    y: i32,
+}
+
+/// `x|y`: page `x` has to come before page `y`.
+fn rule<'a>() -> impl Parser<'a, Rule> {
+    (uint(), literal("|"), uint()).map(|(x, _, y)| Rule { x, y })
+}
+
+/// Whether the update follows every rule about the pages that are in it.
+fn is_ordered(update: &[i32], rules: &[Rule]) -> bool {
+    rules.iter().all(|rule| {
+        let id_x = update.iter().position(|&x| x == rule.x);
+        let id_y = update.iter().position(|&x| x == rule.y);
+
+        match (id_x, id_y) {
+            (Some(id_x), Some(id_y)) => id_x < id_y,
+            _ => true,
+        }
+    })
+}
+
+fn part1(rules: &[Rule], updates: &[Vec<i32>]) -> i32 {
+    updates
+        .iter()
+        .filter(|update| is_ordered(update, rules))
+        .map(|update| update[update.len() / 2])
+        .sum()
+}
+
+fn part2(rules: &[Rule], updates: &[Vec<i32>]) -> i32 {
+    updates
+        .iter()
+        .filter(|update| !is_ordered(update, rules))
+        .map(|update| {
+            let mut update = update.clone();
+            let mut is_valid = false;
+            while !is_valid {
+                rules.iter().for_each(|rule| {
+                    let id_x = update.iter().position(|&x| x == rule.x);
+                    let id_y = update.iter().position(|&x| x == rule.y);
+
+                    if let (Some(id_x), Some(id_y)) = (id_x, id_y) {
+                        if id_x > id_y {
+                            update.swap(id_x, id_y);
+                        }
+                    }
+                });
+
+                is_valid = is_ordered(&update, rules);
+            }
+
+            update
+        })
+        .map(|update| update[update.len() / 2])
+        .sum()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    /// The ordering rules and the updates.
+    type Input = (Vec<Rule>, Vec<Vec<i32>>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rules, _, updates) = combinator::parse(
+            input,
+            (
+                lines(rule()),
+                literal("\n\n"),
+                lines(separated(uint(), literal(","))),
+            ),
+        )?;
+
+        Ok((rules, updates))
+    }
+
+    fn part1((rules, updates): &Self::Input) -> Result<impl Display> {
+        Ok(part1(rules, updates))
+    }
+
+    fn part2((rules, updates): &Self::Input) -> Result<impl Display> {
+        Ok(part2(rules, updates))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 5, sample: [143, 123], real: [5452, 4598]);
+}
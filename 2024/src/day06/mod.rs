@@ -0,0 +1,152 @@
+use std::{collections::HashSet, fmt::Display};
+
+use anyhow::{ensure, Result};
+use aoc_common::{
+    combinator::{self, cells, lines},
+    grid::{Direction, Grid, Point},
+    solution::Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CellType {
+    Empty,
+    Obstacle,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Cell {
+    r#type: CellType,
+    visited: bool,
+}
+
+impl Cell {
+    fn from_char(c: char) -> Option<Self> {
+        let r#type = match c {
+            '#' => CellType::Obstacle,
+            // the guard is kept track of separately, so it stands on an empty cell
+            '.' | '^' => CellType::Empty,
+            _ => return None,
+        };
+
+        Some(Cell {
+            r#type,
+            visited: false,
+        })
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct Guard {
+    location: Point,
+    direction: Direction,
+}
+
+impl Guard {
+    /// Takes one step, turning right instead if there is an obstacle ahead. Returns `false`
+    /// once the guard walks off the grid.
+    fn step(&mut self, grid: &Grid<Cell>) -> bool {
+        let Some(next_location) = grid.step(self.location, self.direction) else {
+            return false;
+        };
+
+        if grid[next_location].r#type == CellType::Empty {
+            self.location = next_location;
+        } else {
+            self.direction = self.direction.turn_right();
+        }
+        true
+    }
+}
+
+fn part1(grid: &Grid<Cell>, guard: &Guard) -> i32 {
+    let (mut grid, mut guard) = (grid.clone(), *guard);
+
+    // Mark the starting cell as visited
+    grid[guard.location].visited = true;
+    while guard.step(&grid) {
+        grid[guard.location].visited = true;
+    }
+
+    grid.iter().filter(|(_, c)| c.visited).count() as i32
+}
+
+fn part2(grid: &Grid<Cell>, guard: &Guard) -> i32 {
+    let (mut grid, mut guard) = (grid.clone(), *guard);
+    let original_guard = guard;
+
+    // Find all the positions the guard visits
+    grid[guard.location].visited = true;
+    while guard.step(&grid) {
+        grid[guard.location].visited = true;
+    }
+
+    let mut ans = 0;
+
+    for point in grid.points() {
+        if !grid[point].visited || point == original_guard.location {
+            continue;
+        }
+
+        guard = original_guard;
+        let mut prev_guard_states = HashSet::new();
+        prev_guard_states.insert(guard);
+
+        grid[point].r#type = CellType::Obstacle;
+
+        while guard.step(&grid) {
+            if prev_guard_states.contains(&guard) {
+                ans += 1;
+                break;
+            }
+            prev_guard_states.insert(guard);
+        }
+
+        grid[point].r#type = CellType::Empty;
+    }
+
+    ans
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    /// The map and where the guard starts.
+    type Input = (Grid<Cell>, Guard);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let grid = Grid::from_rows(combinator::parse(
+            input,
+            lines(cells(Cell::from_char, "'.', '#' or '^'")),
+        )?)?;
+
+        let guards: Vec<Point> = input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| line.match_indices('^').map(move |(x, _)| Point::new(x, y)))
+            .collect();
+        ensure!(
+            guards.len() == 1,
+            "expected one guard, found {}",
+            guards.len()
+        );
+        let guard = Guard {
+            location: guards[0],
+            direction: Direction::Up,
+        };
+
+        Ok((grid, guard))
+    }
+
+    fn part1((grid, guard): &Self::Input) -> Result<impl Display> {
+        Ok(part1(grid, guard))
+    }
+
+    fn part2((grid, guard): &Self::Input) -> Result<impl Display> {
+        Ok(part2(grid, guard))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 6, sample: [41, 6], real: [4988, 1697]);
+}
@@ -0,0 +1,122 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{
+    combinator::{self, lines, literal, separated, uint, Parser},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Equation {
+    result: i128,
+    operands: Vec<i128>,
+}
+
+impl Equation {
+    fn is_valid_1(&self) -> bool {
+        if self.operands.len() == 1 {
+            return self.result == self.operands[0];
+        }
+
+        // If last operand was a addition
+        let mut sub_eqn = Equation {
+            result: self.result - self.operands[self.operands.len() - 1],
+            operands: self.operands[..self.operands.len() - 1].to_vec(),
+        };
+        if sub_eqn.is_valid_1() {
+            return true;
+        }
+
+        // If last operand was a multiplication
+        if self.result % self.operands[self.operands.len() - 1] != 0 {
+            return false;
+        }
+        sub_eqn.result = self.result / self.operands[self.operands.len() - 1];
+        if sub_eqn.is_valid_1() {
+            return true;
+        }
+
+        false
+    }
+
+    fn is_valid_2(&self) -> bool {
+        if self.operands.len() == 1 {
+            return self.result == self.operands[0];
+        }
+
+        // If last operation was addition
+        let mut sub_eqn = Equation {
+            result: self.result - self.operands[self.operands.len() - 1],
+            operands: self.operands[..self.operands.len() - 1].to_vec(),
+        };
+        if sub_eqn.is_valid_2() {
+            return true;
+        }
+
+        // If last operation was multiplication
+        if self.result % self.operands[self.operands.len() - 1] == 0 {
+            sub_eqn.result = self.result / self.operands[self.operands.len() - 1];
+            if sub_eqn.is_valid_2() {
+                return true;
+            }
+        }
+
+        // If last operation was concatenation
+        let denominator = i128::pow(
+            10,
+            (self.operands[self.operands.len() - 1] as f64)
+                .log10()
+                .floor() as u32
+                + 1,
+        );
+        if self.result % denominator == self.operands[self.operands.len() - 1] {
+            sub_eqn.result = self.result / denominator;
+            if sub_eqn.is_valid_2() {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+fn part1(equations: &[Equation]) -> i128 {
+    equations
+        .iter()
+        .filter(|eqn| eqn.is_valid_1())
+        .map(|eqn| eqn.result)
+        .sum()
+}
+
+fn part2(equations: &[Equation]) -> i128 {
+    equations
+        .iter()
+        .filter(|eqn| eqn.is_valid_2())
+        .map(|eqn| eqn.result)
+        .sum()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let equation = (uint(), literal(": "), separated(uint(), literal(" ")))
+            .map(|(result, _, operands)| Equation { result, operands });
+        combinator::parse(input, lines(equation))
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 7, sample: [3749, 11387], real: [975671981569, 223472064194845]);
+}
@@ -0,0 +1,150 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use anyhow::Result;
+use aoc_common::{
+    combinator::{self, cells, lines},
+    grid::{Grid, Point},
+    solution::Solution,
+};
+
+fn in_bounds(position: &Point<i64>, grid_size: &(i64, i64)) -> bool {
+    (0..grid_size.0).contains(&position.x) && (0..grid_size.1).contains(&position.y)
+}
+
+#[derive(Debug)]
+pub struct Map {
+    grid_size: (i64, i64),
+    /// Where the antennae of each frequency are.
+    antennae_groups: HashMap<char, Vec<Point<i64>>>,
+}
+
+/// Letters and digits are antennae, and `.` is nothing.
+fn cell(c: char) -> Option<Option<char>> {
+    match c {
+        '.' => Some(None),
+        c if c.is_ascii_alphanumeric() => Some(Some(c)),
+        _ => None,
+    }
+}
+
+fn part1(map: &Map) -> usize {
+    let Map {
+        grid_size,
+        antennae_groups,
+    } = map;
+
+    let mut anti_nodes = HashSet::new();
+    for (_, positions) in antennae_groups.iter() {
+        for (a1, a2) in positions
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a1)| positions.iter().skip(i + 1).map(move |a2| (a1, a2)))
+        {
+            // Because of the way we get the pairs (a1, a2), we will always have a1 < a2
+            let diff = *a2 - *a1;
+
+            let anti_node = *a1 - diff;
+            if in_bounds(&anti_node, grid_size) {
+                anti_nodes.insert(anti_node);
+            }
+
+            let anti_node = *a2 + diff;
+            if in_bounds(&anti_node, grid_size) {
+                anti_nodes.insert(anti_node);
+            }
+        }
+    }
+
+    anti_nodes.len()
+}
+
+fn part2(map: &Map) -> usize {
+    let Map {
+        grid_size,
+        antennae_groups,
+    } = map;
+
+    let mut anti_nodes = HashSet::new();
+    for (_, positions) in antennae_groups.iter() {
+        for position in positions.iter() {
+            anti_nodes.insert(*position);
+        }
+
+        for (a1, a2) in positions
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a1)| positions.iter().skip(i + 1).map(move |a2| (a1, a2)))
+        {
+            // Because of the way we get the pairs (a1, a2), we will always have a1 < a2
+            let diff = *a2 - *a1;
+
+            let mut n = 1;
+            loop {
+                let mut nodes_added = 0;
+
+                let anti_node = *a1 - (diff * n);
+                if in_bounds(&anti_node, grid_size) {
+                    anti_nodes.insert(anti_node);
+                    nodes_added += 1;
+                }
+
+                let anti_node = *a2 + (diff * n);
+                if in_bounds(&anti_node, grid_size) {
+                    anti_nodes.insert(anti_node);
+                    nodes_added += 1;
+                }
+
+                if nodes_added == 0 {
+                    break;
+                }
+                n += 1;
+            }
+        }
+    }
+
+    anti_nodes.len()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let grid = Grid::from_rows(combinator::parse(
+            input,
+            lines(cells(cell, "an antenna or '.'")),
+        )?)?;
+
+        let mut antennae_groups: HashMap<char, Vec<Point<i64>>> = HashMap::new();
+        for (point, frequency) in grid.iter() {
+            if let Some(frequency) = frequency {
+                antennae_groups
+                    .entry(*frequency)
+                    .or_default()
+                    .push(Point::new(point.x as i64, point.y as i64));
+            }
+        }
+
+        Ok(Map {
+            grid_size: (grid.width() as i64, grid.height() as i64),
+            antennae_groups,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 8, sample: [14, 34], real: [214, 809]);
+}
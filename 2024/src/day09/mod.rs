@@ -0,0 +1,161 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{
+    combinator::{self, cells},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BlockType {
+    File,
+    Empty,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Block {
+    id: usize,
+    length: usize,
+    r#type: BlockType,
+}
+
+fn part1(lengths: &[usize]) -> usize {
+    let mut blocks: Vec<Block> = lengths
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &length)| {
+            let r#type = if i % 2 == 0 {
+                BlockType::File
+            } else {
+                BlockType::Empty
+            };
+            vec![
+                Block {
+                    id: i / 2,
+                    length,
+                    r#type
+                };
+                length
+            ]
+        })
+        .collect();
+
+    let total_empty_spots = blocks
+        .iter()
+        .filter(|block| block.r#type == BlockType::Empty)
+        .count();
+
+    let mut num_empty_blocks_at_end = 0;
+    for i in 0..blocks.len() - total_empty_spots {
+        if blocks[i].r#type != BlockType::Empty {
+            continue;
+        }
+
+        let mut block_to_move = blocks.len() - 1 - num_empty_blocks_at_end;
+        while blocks[block_to_move].r#type == BlockType::Empty {
+            num_empty_blocks_at_end += 1;
+            block_to_move = blocks.len() - 1 - num_empty_blocks_at_end;
+        }
+
+        blocks.swap(i, block_to_move);
+    }
+
+    // for block in blocks.iter() {
+    //     match block.r#type {
+    //         BlockType::File => print!("{}", block.id),
+    //         BlockType::Empty => print!("."),
+    //     };
+    // }
+    // println!();
+
+    blocks
+        .iter()
+        .enumerate()
+        .filter(|(_, block)| block.r#type != BlockType::Empty)
+        .map(|(i, block)| i * block.id)
+        .sum()
+}
+
+fn part2(lengths: &[usize]) -> usize {
+    let mut blocks: Vec<Block> = lengths
+        .iter()
+        .enumerate()
+        .map(|(i, &length)| {
+            let r#type = if i % 2 == 0 {
+                BlockType::File
+            } else {
+                BlockType::Empty
+            };
+            Block {
+                id: i / 2,
+                length,
+                r#type,
+            }
+        })
+        .collect();
+
+    'outer: for i in 0..blocks.len() {
+        if blocks[i].r#type != BlockType::Empty {
+            continue;
+        }
+
+        let mut num_blocks_at_end = 0;
+        let mut block_to_move = blocks.len() - 1 - num_blocks_at_end;
+        while blocks[block_to_move].r#type == BlockType::Empty
+            || blocks[block_to_move].length > blocks[i].length
+        {
+            if i == block_to_move {
+                continue 'outer;
+            }
+            num_blocks_at_end += 1;
+            block_to_move = blocks.len() - 1 - num_blocks_at_end;
+        }
+
+        blocks[i].length -= blocks[block_to_move].length;
+        blocks.insert(i, blocks[block_to_move]);
+        blocks[block_to_move + 1].r#type = BlockType::Empty;
+    }
+
+    // for block in blocks.iter() {
+    //     match block.r#type {
+    //         BlockType::File => print!("{}", format!("{}", block.id).repeat(block.length)),
+    //         BlockType::Empty => print!("{}", format!(".").repeat(block.length)),
+    //     };
+    // }
+    // println!();
+
+    blocks
+        .iter()
+        .flat_map(|block| vec![block; block.length])
+        .enumerate()
+        .filter(|(_, block)| block.r#type != BlockType::Empty)
+        .map(|(i, block)| i * block.id)
+        .sum()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    /// The length of each file and of the free space after it, one after the other.
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        combinator::parse(
+            input,
+            cells(|c| c.to_digit(10).map(|d| d as usize), "a digit"),
+        )
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 9, sample: [1928, 2858], real: [6331212425418, 6363268339304]);
+}
@@ -0,0 +1,72 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{
+    combinator::{self, cells, lines},
+    grid::{Grid, Point},
+    search::{grid_successors, reachable},
+    solution::Solution,
+};
+
+/// Trails climb exactly one step of height at a time.
+fn is_uphill(from: &u32, to: &u32) -> bool {
+    *to == from + 1
+}
+
+fn part1(topology: &Grid<u32>) -> usize {
+    topology
+        .iter()
+        .filter(|(_, height)| **height == 0)
+        .map(|(trailhead, _)| {
+            reachable(trailhead, grid_successors(topology, is_uphill))
+                .into_iter()
+                .filter(|point| topology[*point] == 9)
+                .count()
+        })
+        .sum()
+}
+
+fn get_trailhead_rating(topology: &Grid<u32>, start: Point) -> i64 {
+    if topology[start] == 9 {
+        return 1;
+    }
+
+    grid_successors(topology, is_uphill)(&start)
+        .into_iter()
+        .map(|next| get_trailhead_rating(topology, next))
+        .sum()
+}
+
+fn part2(topology: &Grid<u32>) -> i64 {
+    topology
+        .iter()
+        .filter(|(_, height)| **height == 0)
+        .map(|(trailhead, _)| get_trailhead_rating(topology, trailhead))
+        .sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::from_rows(combinator::parse(
+            input,
+            lines(cells(|c| c.to_digit(10), "a digit")),
+        )?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 10, sample: [36, 81], real: [548, 1252]);
+}
@@ -0,0 +1,63 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{
+    combinator::{self, literal, separated, uint},
+    memo::Memo,
+    solution::Solution,
+};
+use rayon::prelude::*;
+
+fn get_num_stones(memo: &Memo<(usize, usize), usize>, stone: usize, num_iters: usize) -> usize {
+    if num_iters == 0 {
+        return 1;
+    }
+
+    memo.get_or_insert_with((stone, num_iters), || {
+        if stone == 0 {
+            get_num_stones(memo, 1, num_iters - 1)
+        } else if (((stone as f64).log10().floor() + 1.0) as usize).is_multiple_of(2) {
+            let num_digits = (stone as f64).log10().floor() + 1.0;
+            let left = stone / 10usize.pow((num_digits / 2.0) as u32);
+            let right = stone % 10usize.pow((num_digits / 2.0) as u32);
+
+            Vec::from([left, right])
+                .into_par_iter()
+                .map(|x| get_num_stones(memo, x, num_iters - 1))
+                .sum()
+        } else {
+            get_num_stones(memo, stone * 2024, num_iters - 1)
+        }
+    })
+}
+
+fn solve(stones: &[usize], num_iters: usize) -> usize {
+    let memo = Memo::new();
+    stones
+        .par_iter()
+        .map(|&stone| get_num_stones(&memo, stone, num_iters))
+        .sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        combinator::parse(input, separated(uint(), literal(" ")))
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(solve(input, 25))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(solve(input, 75))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 11, sample: [55312, 65601038650482], real: [212655, 253582809724830]);
+}
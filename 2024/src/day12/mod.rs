@@ -0,0 +1,41 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{grid::Grid, solution::Solution};
+
+fn part1(grid: &Grid<char>) -> usize {
+    grid.regions(|a, b| a == b)
+        .iter()
+        .map(|region| region.area() * region.perimeter())
+        .sum()
+}
+
+fn part2(grid: &Grid<char>) -> usize {
+    grid.regions(|a, b| a == b)
+        .iter()
+        .map(|region| region.area() * region.sides())
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::from_str(input, |c| c)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 12, sample: [1930, 1206], real: [1449902, 908042]);
+}
@@ -0,0 +1,131 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{
+    combinator::{self, int, literal, sections, Parser},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Machine {
+    move_by_a: (i64, i64),
+    move_by_b: (i64, i64),
+    prize_location: (i64, i64),
+}
+
+fn coordinates<'a>(x: &'static str, y: &'static str) -> impl Parser<'a, (i64, i64)> {
+    (literal(x), int(), literal(y), int()).map(|(_, x, _, y)| (x, y))
+}
+
+fn machine<'a>() -> impl Parser<'a, Machine> {
+    (
+        coordinates("Button A: X+", ", Y+"),
+        coordinates("\nButton B: X+", ", Y+"),
+        coordinates("\nPrize: X=", ", Y="),
+    )
+        .map(|(move_by_a, move_by_b, prize_location)| Machine {
+            move_by_a,
+            move_by_b,
+            prize_location,
+        })
+}
+
+const COST: (i64, i64) = (3, 1);
+
+impl Machine {
+    fn get_num_moves_required_1(&self) -> Option<(i64, i64)> {
+        let mut moves = (0, 0);
+
+        for i in 0..=100 {
+            for j in 0..=100 {
+                let new_location = (
+                    i * self.move_by_a.0 + j * self.move_by_b.0,
+                    i * self.move_by_a.1 + j * self.move_by_b.1,
+                );
+                if new_location == self.prize_location
+                    && (moves == (0, 0) || i * COST.0 + j * COST.1 < moves.0 * 3 + moves.1)
+                {
+                    moves = (i, j);
+                }
+            }
+        }
+
+        if moves == (0, 0) {
+            None
+        } else {
+            Some(moves)
+        }
+    }
+
+    fn get_num_moves_required_2(&self) -> Option<(i64, i64)> {
+        let det =
+            (self.move_by_a.0 * self.move_by_b.1 - self.move_by_a.1 * self.move_by_b.0) as f64;
+        if det == 0.0 {
+            return None;
+        }
+
+        let moves = (
+            ((self.prize_location.0 * self.move_by_b.1 - self.prize_location.1 * self.move_by_b.0)
+                as f64
+                / det),
+            ((self.move_by_a.0 * self.prize_location.1 - self.move_by_a.1 * self.prize_location.0)
+                as f64
+                / det),
+        );
+        if moves.0 < 0.0
+            || moves.1 < 0.0
+            || moves.0.trunc() != moves.0
+            || moves.1.trunc() != moves.1
+        {
+            return None;
+        }
+
+        Some((moves.0 as i64, moves.1 as i64))
+    }
+}
+
+fn part1(machines: &[Machine]) -> i64 {
+    machines
+        .iter()
+        .filter_map(|f| f.get_num_moves_required_1())
+        .map(|(a, b)| a * COST.0 + b * COST.1)
+        .sum()
+}
+
+fn part2(machines: &[Machine]) -> i64 {
+    machines
+        .iter()
+        .map(|machine| Machine {
+            prize_location: (
+                machine.prize_location.0 + 10000000000000,
+                machine.prize_location.1 + 10000000000000,
+            ),
+            ..*machine
+        })
+        .filter_map(|f| f.get_num_moves_required_2())
+        .map(|(a, b)| a * COST.0 + b * COST.1)
+        .sum()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        combinator::parse(input, sections(machine()))
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 13, sample: [480, 875318608908], real: [32041, 95843948914827]);
+}
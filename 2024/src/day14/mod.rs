@@ -0,0 +1,172 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{bail, Context, Result};
+use aoc_common::{
+    debug,
+    error::SolveError,
+    grid::{Grid, Point},
+    math, parse, scan,
+    solution::Solution,
+    unbounded::TiledGrid,
+};
+
+/// The size of the floor, which is not part of the input.
+const REAL_SIZE: (usize, usize) = (101, 103);
+const SAMPLE_SIZE: (usize, usize) = (11, 7);
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    pos: Point<i64>,
+    vel: Point<i64>,
+}
+
+impl FromStr for Robot {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (x, y, dx, dy) = scan!(s, "p={},{} v={},{}" => i64, i64, i64, i64)?;
+        Ok(Robot {
+            pos: Point::new(x, y),
+            vel: Point::new(dx, dy),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Restroom {
+    width: usize,
+    height: usize,
+    robots: Vec<Robot>,
+}
+
+impl Restroom {
+    fn parse(input: &str, (width, height): (usize, usize)) -> Result<Restroom> {
+        let robots: Vec<Robot> = parse::lines(input)?;
+        if let Some(robot) = robots.iter().find(|robot| {
+            !(0..width as i64).contains(&robot.pos.x) || !(0..height as i64).contains(&robot.pos.y)
+        }) {
+            bail!(
+                "a robot starts at {:?}, off the {}x{} floor",
+                robot.pos,
+                width,
+                height
+            );
+        }
+
+        Ok(Restroom {
+            width,
+            height,
+            robots,
+        })
+    }
+
+    /// The number of robots on each tile. The robots wrap around the edges, which is the same as
+    /// the floor repeating forever in every direction.
+    fn floor(&self) -> Result<TiledGrid<i64>> {
+        let mut floor = TiledGrid::new(Grid::new(self.width, self.height, 0))?;
+        for robot in &self.robots {
+            floor[robot.pos] += 1;
+        }
+
+        Ok(floor)
+    }
+}
+
+/// Whether there are 10 robots in a row.
+fn has_line(cells: impl Iterator<Item = i64>) -> bool {
+    let mut count = 0;
+    for cell in cells {
+        if cell >= 1 {
+            count += 1;
+            if count >= 10 {
+                return true;
+            }
+        } else {
+            count = 0;
+        }
+    }
+
+    false
+}
+
+fn part1(restroom: &Restroom) -> Result<i64> {
+    let (width, height) = (restroom.width, restroom.height);
+
+    let mut floor = TiledGrid::new(Grid::new(width, height, 0))?;
+    for robot in &restroom.robots {
+        floor[robot.pos + robot.vel * 100] += 1;
+    }
+
+    // The robots on the center column and row are not in any quadrant
+    let mut quadrants = [0; 4];
+    for (point, robots) in floor.tile().iter() {
+        if point.x == width / 2 || point.y == height / 2 {
+            continue;
+        }
+        quadrants[usize::from(point.x > width / 2) + 2 * usize::from(point.y > height / 2)] +=
+            robots;
+    }
+
+    Ok(quadrants.iter().product())
+}
+
+fn part2(restroom: &Restroom) -> Result<u64> {
+    let mut robots = restroom.robots.clone();
+    let mut floor = restroom.floor()?;
+    let width = restroom.width;
+
+    // Each robot is back in the same column every `width` seconds and in the same row every
+    // `height` seconds, so they are all back where they started after the lcm of the two
+    let period =
+        math::lcm(restroom.width as u64, restroom.height as u64).context("the floor is too big")?;
+
+    for seconds in 1..=period {
+        for robot in robots.iter_mut() {
+            floor[robot.pos] -= 1;
+            robot.pos = robot.pos + robot.vel;
+            floor[robot.pos] += 1;
+        }
+
+        let tile = floor.tile();
+        if tile.rows().any(|row| has_line(row.iter().copied()))
+            || (0..width).any(|x| has_line(tile.column(x).copied()))
+        {
+            if debug::enabled() {
+                eprint!(
+                    "{}",
+                    tile.render(|robots| if *robots > 0 { '#' } else { '.' })
+                );
+            }
+            return Ok(seconds);
+        }
+    }
+
+    Err(SolveError::no_solution("the robots never line up into a christmas tree").into())
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Restroom;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Restroom::parse(input, REAL_SIZE)
+    }
+
+    fn parse_sample(input: &str) -> Result<Self::Input> {
+        Restroom::parse(input, SAMPLE_SIZE)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 14, sample: [12, _], real: [223020000, 7338]);
+}
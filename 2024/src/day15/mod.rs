@@ -0,0 +1,217 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::Display,
+};
+
+use anyhow::{ensure, Result};
+use aoc_common::{
+    combinator::{self, cells, lines, literal},
+    error::SolveError,
+    grid::{Direction, Grid, Point},
+    solution::Solution,
+};
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    Wall,
+    Block,
+}
+
+/// A tile of the twice as wide warehouse in part 2, where every box takes up two tiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WideTile {
+    Empty,
+    Wall,
+    BlockLeft,
+    BlockRight,
+}
+
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    map: Grid<Tile>,
+    robot: Point,
+    moves: Vec<Direction>,
+}
+
+/// The warehouse is meant to be surrounded by walls, so neither the robot nor a box can ever
+/// step off of it.
+fn step(location: Point, direction: Direction) -> Result<Point> {
+    location
+        .checked_add(direction.offset())
+        .ok_or_else(|| SolveError::no_solution("stepped out of the warehouse").into())
+}
+
+fn part1(warehouse: &Warehouse) -> Result<usize> {
+    let mut map = warehouse.map.clone();
+    let mut bot_location = warehouse.robot;
+
+    'outer: for &r#move in &warehouse.moves {
+        let mut new_location = step(bot_location, r#move)?;
+        let Some(new_tile) = map.get(&new_location) else {
+            continue;
+        };
+
+        match *new_tile {
+            Tile::Wall => continue,
+            Tile::Empty => bot_location = new_location,
+            Tile::Block => {
+                new_location = step(new_location, r#move)?;
+                while let Some(new_tile) = map.get(&new_location) {
+                    if *new_tile == Tile::Wall {
+                        continue 'outer;
+                    }
+                    if *new_tile == Tile::Empty {
+                        break;
+                    }
+                    new_location = step(new_location, r#move)?;
+                }
+
+                match r#move {
+                    Direction::Left => {
+                        for x in new_location.x..bot_location.x - 1 {
+                            map.set(Point::new(x, bot_location.y), Tile::Block);
+                        }
+                        map.set(Point::new(bot_location.x - 1, bot_location.y), Tile::Empty);
+                    }
+                    Direction::Right => {
+                        for x in bot_location.x + 2..=new_location.x {
+                            map.set(Point::new(x, bot_location.y), Tile::Block);
+                        }
+                        map.set(Point::new(bot_location.x + 1, bot_location.y), Tile::Empty);
+                    }
+                    Direction::Up => {
+                        for y in new_location.y..bot_location.y - 1 {
+                            map.set(Point::new(bot_location.x, y), Tile::Block);
+                        }
+                        map.set(Point::new(bot_location.x, bot_location.y - 1), Tile::Empty);
+                    }
+                    Direction::Down => {
+                        for y in bot_location.y + 2..=new_location.y {
+                            map.set(Point::new(bot_location.x, y), Tile::Block);
+                        }
+                        map.set(Point::new(bot_location.x, bot_location.y + 1), Tile::Empty);
+                    }
+                }
+                bot_location = step(bot_location, r#move)?;
+            }
+        }
+    }
+
+    Ok(map
+        .iter()
+        .filter(|(_, tile)| **tile == Tile::Block)
+        .map(|(point, _)| point.y * 100 + point.x)
+        .sum())
+}
+
+fn part2(warehouse: &Warehouse) -> Result<usize> {
+    // everything except the robot is twice as wide for part 2
+    let mut map = Grid::from_fn(
+        warehouse.map.width() * 2,
+        warehouse.map.height(),
+        |point| match (warehouse.map[Point::new(point.x / 2, point.y)], point.x % 2) {
+            (Tile::Empty, _) => WideTile::Empty,
+            (Tile::Wall, _) => WideTile::Wall,
+            (Tile::Block, 0) => WideTile::BlockLeft,
+            (Tile::Block, _) => WideTile::BlockRight,
+        },
+    );
+    let mut bot_location = Point::new(warehouse.robot.x * 2, warehouse.robot.y);
+
+    'outer: for &r#move in &warehouse.moves {
+        let new_location = step(bot_location, r#move)?;
+
+        // Both halves of every box in the way, and of every box in the way of those, and so on.
+        // The robot cannot move at all if any of them would be pushed into a wall.
+        let mut to_check = VecDeque::from([new_location]);
+        let mut to_move = HashSet::new();
+        while let Some(loc) = to_check.pop_front() {
+            let other_half = match map.get(&loc) {
+                None | Some(WideTile::Wall) => continue 'outer,
+                Some(WideTile::Empty) => continue,
+                Some(WideTile::BlockLeft) => Direction::Right,
+                Some(WideTile::BlockRight) => Direction::Left,
+            };
+            if !to_move.insert(loc) {
+                continue;
+            }
+            to_check.push_back(step(loc, other_half)?);
+            to_check.push_back(step(loc, r#move)?);
+        }
+
+        // Clear all of the boxes before putting them back, so that none of them is overwritten
+        // by the one behind it
+        let blocks: Vec<_> = to_move.into_iter().map(|loc| (loc, map[loc])).collect();
+        for &(loc, _) in &blocks {
+            map.set(loc, WideTile::Empty);
+        }
+        for (loc, tile) in blocks {
+            map.set(step(loc, r#move)?, tile);
+        }
+        bot_location = new_location;
+    }
+
+    Ok(map
+        .iter()
+        .filter(|(_, tile)| **tile == WideTile::BlockLeft)
+        .map(|(point, _)| point.y * 100 + point.x)
+        .sum())
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Warehouse;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let tile = |c| match c {
+            '#' => Some(Tile::Wall),
+            'O' => Some(Tile::Block),
+            '.' => Some(Tile::Empty),
+            // the robot is kept track of separately, so it stands on an empty tile
+            '@' => Some(Tile::Empty),
+            _ => None,
+        };
+        let arrow = |c| Direction::try_from(c).ok();
+        let (rows, _, moves) = combinator::parse(
+            input,
+            (
+                lines(cells(tile, "a wall, box, robot or empty tile")),
+                literal("\n\n"),
+                lines(cells(arrow, "an arrow")),
+            ),
+        )?;
+
+        let robots = input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| line.match_indices('@').map(move |(x, _)| Point::new(x, y)))
+            .collect_vec();
+        ensure!(
+            robots.len() == 1,
+            "expected one robot, found {}",
+            robots.len()
+        );
+
+        Ok(Warehouse {
+            map: Grid::from_rows(rows)?,
+            robot: robots[0],
+            moves: moves.into_iter().flatten().collect(),
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 15, sample: [10092, 9021], real: [1471826, 1457703]);
+}
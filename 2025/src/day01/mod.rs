@@ -0,0 +1,90 @@
+use std::fmt::Display;
+
+use anyhow::{Result, anyhow};
+use aoc_common::{
+    combinator::{self, Parser, lines, uint, word},
+    solution::Solution,
+};
+
+pub enum Action {
+    Left,
+    Right,
+}
+
+impl TryFrom<&str> for Action {
+    type Error = anyhow::Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        match input {
+            "L" => Ok(Action::Left),
+            "R" => Ok(Action::Right),
+            _ => Err(anyhow!("expected 'L' or 'R', found {:?}", input)),
+        }
+    }
+}
+
+fn part1(actions: &[(Action, i32)]) -> i32 {
+    let mut position = 50;
+    let mut ans = 0;
+    for (action, steps) in actions {
+        match action {
+            Action::Left => position -= steps,
+            Action::Right => position += steps,
+        }
+        if position % 100 == 0 {
+            ans += 1;
+        }
+    }
+
+    ans
+}
+
+fn part2(actions: &[(Action, i32)]) -> i32 {
+    let mut position = 50;
+    let mut ans = 0;
+    for (action, steps) in actions {
+        match action {
+            Action::Left => {
+                for _ in 0..*steps {
+                    position -= 1;
+                    if position % 100 == 0 {
+                        ans += 1;
+                    }
+                }
+            }
+            Action::Right => {
+                for _ in 0..*steps {
+                    position += 1;
+                    if position % 100 == 0 {
+                        ans += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    ans
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<(Action, i32)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        combinator::parse(input, lines((word().try_map(Action::try_from), uint())))
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 1, sample: [3, 6], real: [1145, 6561]);
+}
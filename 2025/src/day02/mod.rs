@@ -0,0 +1,89 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{
+    combinator::{self, Parser, literal, separated, uint},
+    solution::Solution,
+};
+
+pub struct Range {
+    start: u64,
+    end: u64,
+}
+
+fn part1(ranges: &[Range]) -> u64 {
+    let mut ans = 0;
+    for range in ranges {
+        for i in range.start..=range.end {
+            let str_i = i.to_string();
+            if str_i.len() % 2 != 0 {
+                continue;
+            }
+            let mid = str_i.len() / 2;
+            let (left, right) = str_i.split_at(mid);
+            if left == right {
+                ans += i;
+            }
+        }
+    }
+    ans
+}
+
+fn part2(ranges: &[Range]) -> u64 {
+    let mut ans = 0;
+    for range in ranges {
+        for num in range.start..=range.end {
+            let str_i = num.to_string();
+            for part_len in 1..=str_i.len() / 2 {
+                if str_i.len() % part_len != 0 {
+                    continue;
+                }
+
+                let mut is_invalid = true;
+                for part_idx in 0..(str_i.len() / part_len - 1) {
+                    if str_i[part_idx * part_len..(part_idx + 1) * part_len]
+                        != str_i[(part_idx + 1) * part_len..(part_idx + 2) * part_len]
+                    {
+                        is_invalid = false;
+                        break;
+                    }
+                }
+                if is_invalid {
+                    ans += num;
+                    break;
+                }
+            }
+        }
+    }
+    ans
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Range>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let range = (uint(), literal("-"), uint()).try_map(|(start, _, end)| {
+            if start <= end {
+                Ok(Range { start, end })
+            } else {
+                Err(format!("the range {}-{} ends before it starts", start, end))
+            }
+        });
+        combinator::parse(input, separated(range, literal(",")))
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 2, sample: [1227775554, 4174379265], real: [13108371860, 22471660255]);
+}
@@ -0,0 +1,111 @@
+use std::fmt::Display;
+
+use anyhow::{Result, ensure};
+use aoc_common::{
+    combinator::{self, cells, lines},
+    memo::Memo,
+    solution::Solution,
+};
+
+/// The number of batteries to turn on in each bank for part 2.
+const NUM_BATTERIES: u64 = 12;
+
+fn part1(banks: &[Vec<u64>]) -> u64 {
+    let mut ans = 0;
+    for bank in banks {
+        let mut highest_idx = 0;
+        let mut highest_num = bank[0];
+        for (idx, num) in bank.iter().enumerate() {
+            if *num > highest_num {
+                highest_num = *num;
+                highest_idx = idx;
+            }
+        }
+
+        if highest_idx == bank.len() - 1 {
+            let mut second_highest_num = bank[0];
+            for &num in &bank[..bank.len() - 1] {
+                if num > second_highest_num {
+                    second_highest_num = num;
+                }
+            }
+            ans += second_highest_num * 10 + highest_num;
+        } else {
+            let second_highest_num = bank[highest_idx + 1..].iter().max().unwrap();
+            ans += highest_num * 10 + second_highest_num;
+        }
+    }
+    ans
+}
+
+fn get_max_joltage_for_bank(
+    bank: &[u64],
+    num_batteries_to_use: u64,
+    memo: &Memo<(usize, u64), u64>,
+    original_bank_size: usize,
+) -> u64 {
+    if num_batteries_to_use == 0 {
+        return 0;
+    }
+    if bank.len() == num_batteries_to_use as usize {
+        let mut total_joltage = 0;
+        for battery in bank {
+            total_joltage *= 10;
+            total_joltage += *battery;
+        }
+        return total_joltage;
+    }
+
+    let key = (original_bank_size - bank.len(), num_batteries_to_use);
+    memo.get_or_insert_with(key, || {
+        get_max_joltage_for_bank(&bank[1..], num_batteries_to_use, memo, original_bank_size).max(
+            bank[0] * 10_u64.pow((num_batteries_to_use - 1) as u32)
+                + get_max_joltage_for_bank(
+                    &bank[1..],
+                    num_batteries_to_use - 1,
+                    memo,
+                    original_bank_size,
+                ),
+        )
+    })
+}
+
+fn part2(banks: &[Vec<u64>]) -> Result<u64> {
+    let mut ans = 0;
+    for bank in banks {
+        ensure!(
+            bank.len() >= NUM_BATTERIES as usize,
+            "a bank has fewer than {} batteries",
+            NUM_BATTERIES
+        );
+        let memo = Memo::new();
+        ans += get_max_joltage_for_bank(&bank[..], NUM_BATTERIES, &memo, bank.len());
+    }
+    Ok(ans)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<u64>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        combinator::parse(
+            input,
+            lines(cells(|c| c.to_digit(10).map(u64::from), "a digit")),
+        )
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 3, sample: [357, 3121910778619], real: [17158, 170449335646486]);
+}
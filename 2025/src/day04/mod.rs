@@ -0,0 +1,132 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{error::SolveError, solution::Solution};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Block {
+    Roll,
+    Empty,
+}
+
+impl Block {
+    fn from_char(c: char) -> Option<Block> {
+        match c {
+            '@' => Some(Block::Roll),
+            '.' => Some(Block::Empty),
+            _ => None,
+        }
+    }
+}
+
+fn is_roll_removable(i: usize, j: usize, grid: &[Vec<Block>]) -> bool {
+    let mut count = 0;
+    if i > 0 && grid[i - 1][j] == Block::Roll {
+        count += 1;
+    }
+    if i < grid.len() - 1 && grid[i + 1][j] == Block::Roll {
+        count += 1;
+    }
+    if j > 0 && grid[i][j - 1] == Block::Roll {
+        count += 1;
+    }
+    if j < grid[0].len() - 1 && grid[i][j + 1] == Block::Roll {
+        count += 1;
+    }
+    if i > 0 && j > 0 && grid[i - 1][j - 1] == Block::Roll {
+        count += 1;
+    }
+    if i > 0 && j < grid[0].len() - 1 && grid[i - 1][j + 1] == Block::Roll {
+        count += 1;
+    }
+    if i < grid.len() - 1 && j > 0 && grid[i + 1][j - 1] == Block::Roll {
+        count += 1;
+    }
+    if i < grid.len() - 1 && j < grid[0].len() - 1 && grid[i + 1][j + 1] == Block::Roll {
+        count += 1;
+    }
+    count < 4
+}
+
+fn part1(grid: &[Vec<Block>]) -> u32 {
+    let mut ans = 0;
+    for i in 0..grid.len() {
+        for j in 0..grid[0].len() {
+            if grid[i][j] != Block::Roll {
+                continue;
+            }
+
+            if is_roll_removable(i, j, grid) {
+                ans += 1;
+            }
+        }
+    }
+
+    ans
+}
+
+fn part2(grid: &[Vec<Block>]) -> u32 {
+    let mut grid = grid.to_vec();
+    let mut ans = 0;
+    loop {
+        let mut any_removed = false;
+        for i in 0..grid.len() {
+            for j in 0..grid[0].len() {
+                if grid[i][j] != Block::Roll {
+                    continue;
+                }
+
+                if is_roll_removable(i, j, &grid) {
+                    ans += 1;
+                    grid[i][j] = Block::Empty;
+                    any_removed = true;
+                }
+            }
+        }
+
+        if !any_removed {
+            break;
+        }
+    }
+
+    ans
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Vec<Block>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| {
+                        Block::from_char(c).ok_or_else(|| SolveError::Parse {
+                            line: y + 1,
+                            column: x + 1,
+                            message: format!("expected '@' or '.', found {:?}", c),
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()
+            .map_err(Into::into)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 4, sample: [13, 43], real: [1460, 9243]);
+}
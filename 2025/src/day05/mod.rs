@@ -0,0 +1,85 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::{
+    combinator::{self, Parser, lines, literal, uint},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone)]
+pub struct Range {
+    min: u64,
+    max: u64,
+}
+
+fn part1(ranges: &[Range], ids: &[u64]) -> u64 {
+    let mut ans = 0;
+    for id in ids {
+        let mut valid = false;
+        for range in ranges {
+            if *id >= range.min && *id <= range.max {
+                valid = true;
+                break;
+            }
+        }
+        if valid {
+            ans += 1;
+        }
+    }
+
+    ans
+}
+
+fn part2(ranges: &[Range]) -> u64 {
+    let mut sorted_ranges = ranges.to_vec();
+    sorted_ranges.sort_by_key(|r| r.min);
+
+    let mut collapsed_ranges: Vec<Range> = vec![sorted_ranges[0].clone()];
+    let mut current_end = collapsed_ranges[0].max;
+    for range in sorted_ranges {
+        if range.min > current_end + 1 {
+            collapsed_ranges.push(Range {
+                min: range.min,
+                max: range.max,
+            });
+            current_end = range.max;
+        } else if range.max > current_end {
+            current_end = range.max;
+            collapsed_ranges.last_mut().unwrap().max = current_end;
+        }
+    }
+
+    let mut ans = 0;
+    for range in collapsed_ranges {
+        ans += range.max - range.min + 1;
+    }
+
+    ans
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Vec<Range>, Vec<u64>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let range = (uint(), literal("-"), uint()).map(|(min, _, max)| Range { min, max });
+        let (ranges, _, ids) =
+            combinator::parse(input, (lines(range), literal("\n\n"), lines(uint())))?;
+
+        Ok((ranges, ids))
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display> {
+        Ok(part1(&input.0, &input.1))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display> {
+        Ok(part2(&input.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(crate::YEAR, 5, sample: [3, 14], real: [661, 359526404143208]);
+}
@@ -0,0 +1,19 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+
+use aoc_common::solution::{Day, Year};
+
+pub const YEAR: Year = Year {
+    year: 2025,
+    dir: concat!(env!("CARGO_MANIFEST_DIR"), "/src"),
+    days: &[
+        Day::new::<day01::Day01>(1),
+        Day::new::<day02::Day02>(2),
+        Day::new::<day03::Day03>(3),
+        Day::new::<day04::Day04>(4),
+        Day::new::<day05::Day05>(5),
+    ],
+};
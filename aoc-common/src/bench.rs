@@ -0,0 +1,192 @@
+use std::{fmt::Write, fs, path::Path, time::Duration};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::solution::{read_input, Day, InputSource, Part, Year};
+
+/// How long parsing and each part of a day took in a single run. A part that fails is not timed.
+pub struct Timings {
+    pub parse: Duration,
+    pub parts: [Option<Duration>; 2],
+}
+
+/// The timings of one step of a day over every iteration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    /// `parse`, `part 1` or `part 2`.
+    pub step: String,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+}
+
+impl Measurement {
+    fn new(day: u8, step: &str, samples: &mut [Duration]) -> Self {
+        samples.sort();
+        let nanos = |d: Duration| d.as_nanos() as u64;
+
+        Measurement {
+            day,
+            step: step.to_string(),
+            min_ns: nanos(samples[0]),
+            median_ns: nanos(samples[samples.len() / 2]),
+            mean_ns: nanos(samples.iter().sum::<Duration>() / samples.len() as u32),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Report {
+    pub year: u16,
+    pub iterations: u32,
+    pub measurements: Vec<Measurement>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Self> {
+        let report = fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        serde_json::from_str(&report).with_context(|| format!("invalid report {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    /// Renders the report as a Markdown table. Given a previous report, the medians are compared
+    /// with it and every step that got more than `threshold` (e.g. `0.1` for 10%) slower is
+    /// flagged.
+    pub fn to_markdown(&self, previous: Option<&Report>, threshold: f64) -> String {
+        let mut table = String::from("| Day | Step | Min | Median | Mean |");
+        if previous.is_some() {
+            table.push_str(" Change |");
+        }
+        table.push_str("\n|---|---|---|---|---|");
+        if previous.is_some() {
+            table.push_str("---|");
+        }
+
+        let format = |nanos: u64| format!("{:.2?}", Duration::from_nanos(nanos));
+        for m in &self.measurements {
+            write!(
+                table,
+                "\n| {:02} | {} | {} | {} | {} |",
+                m.day,
+                m.step,
+                format(m.min_ns),
+                format(m.median_ns),
+                format(m.mean_ns)
+            )
+            .unwrap();
+
+            let Some(previous) = previous else {
+                continue;
+            };
+            match previous
+                .measurements
+                .iter()
+                .find(|p| p.day == m.day && p.step == m.step)
+            {
+                Some(p) => {
+                    let change = m.median_ns as f64 / p.median_ns.max(1) as f64 - 1.0;
+                    let flag = if change > threshold { " (slower)" } else { "" };
+                    write!(table, " {:+.1}%{} |", change * 100.0, flag).unwrap();
+                }
+                None => table.push_str(" new |"),
+            }
+        }
+
+        table
+    }
+}
+
+/// Times every given day on its real input, `iterations` times each. A day that cannot be read or
+/// parsed is reported and left out.
+pub fn run(year: &Year, days: &[&Day], iterations: u32) -> Result<Report> {
+    if iterations == 0 {
+        bail!("at least one iteration is needed");
+    }
+
+    let mut measurements = vec![];
+    for day in days {
+        let input = InputSource::Real.path(year, day.day, Part::One);
+        let timings = read_input(input.as_deref()).and_then(|input| {
+            (0..iterations)
+                .map(|_| day.bench(&input))
+                .collect::<Result<Vec<_>>>()
+        });
+        let timings = match timings {
+            Ok(timings) => timings,
+            Err(err) => {
+                eprintln!("Day {:02}: {:#}", day.day, err);
+                continue;
+            }
+        };
+
+        let mut parse: Vec<Duration> = timings.iter().map(|t| t.parse).collect();
+        measurements.push(Measurement::new(day.day, "parse", &mut parse));
+        for part in Part::ALL {
+            let i = match part {
+                Part::One => 0,
+                Part::Two => 1,
+            };
+            let mut samples: Vec<Duration> = timings.iter().filter_map(|t| t.parts[i]).collect();
+            if !samples.is_empty() {
+                let step = format!("part {}", part);
+                measurements.push(Measurement::new(day.day, &step, &mut samples));
+            }
+        }
+    }
+
+    Ok(Report {
+        year: year.year,
+        iterations,
+        measurements,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(medians: &[u64]) -> Report {
+        Report {
+            year: 2024,
+            iterations: 3,
+            measurements: medians
+                .iter()
+                .map(|&median_ns| Measurement {
+                    day: 1,
+                    step: format!("part {}", median_ns),
+                    min_ns: median_ns,
+                    median_ns,
+                    mean_ns: median_ns,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn summarizes_samples() {
+        let mut samples = [3, 1, 8].map(Duration::from_nanos);
+        let m = Measurement::new(1, "parse", &mut samples);
+        assert_eq!((m.min_ns, m.median_ns, m.mean_ns), (1, 3, 4));
+    }
+
+    #[test]
+    fn flags_slowdowns() {
+        let mut current = report(&[1000]);
+        let previous = report(&[1000]);
+        current.measurements[0].median_ns = 1500;
+
+        let table = current.to_markdown(Some(&previous), 0.1);
+        assert!(table.ends_with("| +50.0% (slower) |"), "{}", table);
+
+        current.measurements[0].median_ns = 1050;
+        let table = current.to_markdown(Some(&previous), 0.1);
+        assert!(table.ends_with("| +5.0% |"), "{}", table);
+    }
+}
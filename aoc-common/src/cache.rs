@@ -0,0 +1,56 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+use crate::client::Client;
+
+/// The directory downloaded inputs and the answer history are kept in: `AOC_CACHE_DIR`, or else
+/// the gitignored `inputs` directory at the root of the workspace.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    pub fn from_env() -> Self {
+        match env::var("AOC_CACHE_DIR") {
+            Ok(dir) => Cache::new(dir),
+            Err(_) => Cache::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs")),
+        }
+    }
+
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    /// Where every submitted answer is recorded.
+    pub fn history_path(&self) -> PathBuf {
+        self.dir.join("history.tsv")
+    }
+
+    /// Downloads the input of a day unless it is already cached (or `force` is set), returning
+    /// where it is kept.
+    pub fn fetch_input(&self, client: &Client, year: u16, day: u8, force: bool) -> Result<PathBuf> {
+        let path = self.input_path(year, day);
+        if path.exists() && !force {
+            return Ok(path);
+        }
+
+        let input = client.input(year, day)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+        }
+        fs::write(&path, input).with_context(|| format!("failed to write {}", path.display()))?;
+
+        Ok(path)
+    }
+}
@@ -0,0 +1,244 @@
+use std::{env, fs, path::PathBuf, time::Duration};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::{history::Verdict, solution::Part};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/sethigeet/advent_of_code";
+
+/// A client for the Advent of Code website, logged in through the `session` cookie.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Reads the session token from `AOC_SESSION`, or else from `aoc/session` in the config
+    /// directory, and the base URL from `AOC_BASE_URL`, defaulting to the real website.
+    pub fn from_env() -> Result<Self> {
+        let session = match env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => {
+                let path = config_dir()?.join("aoc").join("session");
+                fs::read_to_string(&path).with_context(|| {
+                    format!(
+                        "no session token, set AOC_SESSION or write it to {}",
+                        path.display()
+                    )
+                })?
+            }
+        };
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+
+        Ok(Client::new(&base_url, &session))
+    }
+
+    /// Downloads the puzzle input of a day.
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(400, _)) => bail!("the session token was rejected"),
+            Err(ureq::Error::Status(404, _)) => {
+                bail!("the puzzle of {} day {} is not unlocked yet", year, day)
+            }
+            Err(err) => Err(err).with_context(|| format!("failed to download {}", url)),
+        }
+    }
+
+    /// Submits the answer of a part.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<Response> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        match response {
+            Ok(response) => Response::parse(&response.into_string()?),
+            Err(ureq::Error::Status(400, _)) => bail!("the session token was rejected"),
+            Err(err) => Err(err).with_context(|| format!("failed to submit to {}", url)),
+        }
+    }
+}
+
+/// The outcome of submitting an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Response {
+    Judged(Verdict),
+    /// The answer was not looked at, another one was submitted too recently.
+    RateLimited(Duration),
+    /// The part was already solved, or is not unlocked yet.
+    WrongLevel,
+}
+
+impl Response {
+    /// Reads the outcome from the page the website answers a submission with.
+    pub fn parse(page: &str) -> Result<Self> {
+        if page.contains("That's the right answer") {
+            Ok(Response::Judged(Verdict::Correct))
+        } else if page.contains("That's not the right answer") {
+            Ok(Response::Judged(
+                if page.contains("your answer is too high") {
+                    Verdict::TooHigh
+                } else if page.contains("your answer is too low") {
+                    Verdict::TooLow
+                } else {
+                    Verdict::Wrong
+                },
+            ))
+        } else if page.contains("You gave an answer too recently") {
+            Ok(Response::RateLimited(parse_wait(page).unwrap_or_default()))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Ok(Response::WrongLevel)
+        } else {
+            Err(anyhow!(
+                "could not make sense of the response to the submission"
+            ))
+        }
+    }
+}
+
+/// Reads the time left from e.g. "You have 1m 12s left to wait".
+fn parse_wait(page: &str) -> Option<Duration> {
+    let (_, rest) = page.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    wait.split_whitespace()
+        .try_fold(Duration::ZERO, |total, amount| {
+            let seconds: u64 = match amount.split_at(amount.len() - 1) {
+                (minutes, "m") => minutes.parse::<u64>().ok()? * 60,
+                (seconds, "s") => seconds.parse().ok()?,
+                _ => return None,
+            };
+            Some(total + Duration::from_secs(seconds))
+        })
+}
+
+fn config_dir() -> Result<PathBuf> {
+    if let Ok(dir) = env::var("XDG_CONFIG_HOME") {
+        return Ok(PathBuf::from(dir));
+    }
+    env::var("HOME")
+        .map(|home| PathBuf::from(home).join(".config"))
+        .map_err(|_| anyhow!("could not find the config directory"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::*;
+
+    /// Serves a single request with the given status and body, returning the base URL to reach it
+    /// and a handle yielding the request line and headers that were received.
+    fn stub_server(status: u16, body: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request: Vec<String> = BufReader::new(&stream)
+                .lines()
+                .map(|line| line.unwrap())
+                .take_while(|line| !line.is_empty())
+                .collect();
+            write!(
+                stream,
+                "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (base_url, server) = stub_server(200, "1 2 3\n");
+        let client = Client::new(&format!("{}/", base_url), "token\n");
+
+        assert_eq!(client.input(2024, 5).unwrap(), "1 2 3\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2024/day/5/input HTTP/1.1");
+        assert!(request.iter().any(|h| h == "Cookie: session=token"));
+    }
+
+    #[test]
+    fn reports_locked_puzzles() {
+        let (base_url, server) = stub_server(404, "Not Found");
+        let client = Client::new(&base_url, "token");
+
+        let err = client.input(2024, 25).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the puzzle of 2024 day 25 is not unlocked yet"
+        );
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn submits_answer() {
+        let (base_url, server) = stub_server(
+            200,
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        );
+        let client = Client::new(&base_url, "token");
+
+        assert_eq!(
+            client.submit(2025, 5, Part::Two, "42").unwrap(),
+            Response::Judged(Verdict::TooLow)
+        );
+        assert_eq!(
+            server.join().unwrap()[0],
+            "POST /2025/day/5/answer HTTP/1.1"
+        );
+    }
+
+    #[test]
+    fn parses_responses() {
+        assert_eq!(
+            Response::parse("That's the right answer! You are one gold star closer.").unwrap(),
+            Response::Judged(Verdict::Correct)
+        );
+        assert_eq!(
+            Response::parse("That's not the right answer. If you're stuck, ...").unwrap(),
+            Response::Judged(Verdict::Wrong)
+        );
+        assert_eq!(
+            Response::parse(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 12s left to wait."
+            )
+            .unwrap(),
+            Response::RateLimited(Duration::from_secs(72))
+        );
+        assert!(Response::parse("<html></html>").is_err());
+    }
+}
@@ -0,0 +1,355 @@
+use std::{borrow::Cow, fmt::Display, str::FromStr};
+
+use anyhow::Result;
+
+use crate::error::SolveError;
+
+/// Where a parser gave up on the input and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    /// The rest of the input from where the parser gave up, which [`parse`] turns into a line and
+    /// column.
+    pub at: &'a str,
+    pub message: Cow<'static, str>,
+}
+
+/// The value a parser found along with the rest of the input.
+pub type Step<'a, T> = std::result::Result<(T, &'a str), Failure<'a>>;
+
+/// Something that reads a `T` off the front of the input. Any `Fn(&str) -> Step<T>` is a
+/// parser, and so are tuples of parsers, which run one after the other.
+pub trait Parser<'a, T> {
+    fn parse_from(&self, input: &'a str) -> Step<'a, T>;
+
+    fn map<U>(self, f: impl Fn(T) -> U) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| {
+            let (value, rest) = self.parse_from(input)?;
+            Ok((f(value), rest))
+        }
+    }
+
+    /// Like [`Parser::map`], but `f` can reject the value, failing where the value started.
+    fn try_map<U, E: Display>(self, f: impl Fn(T) -> Result<U, E>) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| {
+            let (value, rest) = self.parse_from(input)?;
+            match f(value) {
+                Ok(value) => Ok((value, rest)),
+                Err(err) => Err(Failure {
+                    at: input,
+                    message: err.to_string().into(),
+                }),
+            }
+        }
+    }
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(&'a str) -> Step<'a, T>,
+{
+    fn parse_from(&self, input: &'a str) -> Step<'a, T> {
+        self(input)
+    }
+}
+
+macro_rules! impl_tuple_parser {
+    ($(($parser:ident, $value:ident)),+) => {
+        impl<'a, $($parser, $value),+> Parser<'a, ($($value,)+)> for ($($parser,)+)
+        where
+            $($parser: Parser<'a, $value>),+
+        {
+            #[allow(non_snake_case)]
+            fn parse_from(&self, input: &'a str) -> Step<'a, ($($value,)+)> {
+                let ($($parser,)+) = self;
+                let rest = input;
+                $(let ($value, rest) = $parser.parse_from(rest)?;)+
+                Ok((($($value,)+), rest))
+            }
+        }
+    };
+}
+
+impl_tuple_parser!((A, TA), (B, TB));
+impl_tuple_parser!((A, TA), (B, TB), (C, TC));
+impl_tuple_parser!((A, TA), (B, TB), (C, TC), (D, TD));
+impl_tuple_parser!((A, TA), (B, TB), (C, TC), (D, TD), (E, TE));
+impl_tuple_parser!((A, TA), (B, TB), (C, TC), (D, TD), (E, TE), (F, TF));
+
+/// Runs `parser` over the whole input, which may only have whitespace left over at the end. A
+/// failure is reported as a [`SolveError::Parse`] with the line and column it happened at.
+pub fn parse<'a, T>(input: &'a str, parser: impl Parser<'a, T>) -> Result<T> {
+    let failure = match parser.parse_from(input) {
+        Ok((value, rest)) if rest.trim().is_empty() => return Ok(value),
+        Ok((_, rest)) => Failure {
+            at: rest,
+            message: "expected the end of the input".into(),
+        },
+        Err(failure) => failure,
+    };
+
+    let offset = input.len() - failure.at.len();
+    let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = input[offset..]
+        .find('\n')
+        .map_or(input.len(), |i| offset + i);
+    Err(SolveError::Parse {
+        line: input[..offset].matches('\n').count() + 1,
+        column: input[line_start..offset].chars().count() + 1,
+        message: format!("{} in {:?}", failure.message, &input[line_start..line_end]),
+    }
+    .into())
+}
+
+fn take_while(input: &str, f: impl Fn(char) -> bool) -> (&str, &str) {
+    input.split_at(input.find(|c| !f(c)).unwrap_or(input.len()))
+}
+
+/// Exactly the text `expected`.
+pub fn literal<'a>(expected: &'static str) -> impl Parser<'a, ()> {
+    move |input: &'a str| match input.strip_prefix(expected) {
+        Some(rest) => Ok(((), rest)),
+        None => Err(Failure {
+            at: input,
+            message: format!("expected {:?}", expected).into(),
+        }),
+    }
+}
+
+/// Any number of spaces, including none.
+pub fn spaces<'a>() -> impl Parser<'a, ()> {
+    |input: &'a str| Ok(((), input.trim_start_matches(' ')))
+}
+
+/// One or more ASCII letters.
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    |input: &'a str| match take_while(input, |c| c.is_ascii_alphabetic()) {
+        ("", _) => Err(Failure {
+            at: input,
+            message: "expected a word".into(),
+        }),
+        found => Ok(found),
+    }
+}
+
+/// One or more characters up to the next whitespace, e.g. to be turned into a value with
+/// [`Parser::try_map`].
+pub fn token<'a>() -> impl Parser<'a, &'a str> {
+    |input: &'a str| match take_while(input, |c| !c.is_whitespace()) {
+        ("", _) => Err(Failure {
+            at: input,
+            message: "expected a token".into(),
+        }),
+        found => Ok(found),
+    }
+}
+
+fn number<'a, T>(input: &'a str, signed: bool) -> Step<'a, T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let sign = match input.chars().next() {
+        Some('+' | '-') if signed => 1,
+        _ => 0,
+    };
+    let (digits, _) = take_while(&input[sign..], |c| c.is_ascii_digit());
+    let fail = |message: String| Failure {
+        at: input,
+        message: message.into(),
+    };
+
+    if digits.is_empty() {
+        let kind = if signed {
+            "an integer"
+        } else {
+            "an unsigned integer"
+        };
+        return Err(fail(format!("expected {}", kind)));
+    }
+    let (number, rest) = input.split_at(sign + digits.len());
+    match number.parse() {
+        Ok(value) => Ok((value, rest)),
+        Err(err) => Err(fail(format!("invalid number {:?}: {}", number, err))),
+    }
+}
+
+/// A number made up of only digits.
+pub fn uint<'a, T>() -> impl Parser<'a, T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    |input: &'a str| number(input, false)
+}
+
+/// A number made up of digits with an optional `+` or `-` in front.
+pub fn int<'a, T>() -> impl Parser<'a, T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    |input: &'a str| number(input, true)
+}
+
+/// The characters up until the end of the line, each turned into a value by `cell`, e.g. a row of
+/// a map. Fails at the first character that `cell` rejects, or if the line is empty.
+pub fn cells<'a, T>(
+    cell: impl Fn(char) -> Option<T>,
+    expected: &'static str,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (line, rest) = take_while(input, |c| c != '\n');
+        let mut cells = vec![];
+        for (i, c) in line.char_indices() {
+            match cell(c) {
+                Some(value) => cells.push(value),
+                None => {
+                    return Err(Failure {
+                        at: &input[i..],
+                        message: format!("expected {}, found {:?}", expected, c).into(),
+                    })
+                }
+            }
+        }
+
+        if cells.is_empty() {
+            return Err(Failure {
+                at: input,
+                message: format!("expected {}", expected).into(),
+            });
+        }
+        Ok((cells, rest))
+    }
+}
+
+/// The value of `parser` if it matches, or else nothing without using up any input.
+pub fn opt<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    move |input: &'a str| match parser.parse_from(input) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(_) => Ok((None, input)),
+    }
+}
+
+/// One or more of `item` with `separator` between them. An item has to follow every separator,
+/// so the separator should only match where another item is meant to start.
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = item.parse_from(input)?;
+        let mut items = vec![first];
+        while let Ok((_, after)) = separator.parse_from(rest) {
+            let (next, after) = item.parse_from(after)?;
+            items.push(next);
+            rest = after;
+        }
+
+        Ok((items, rest))
+    }
+}
+
+/// A line break that is followed by another line in the same section.
+fn line_break<'a>() -> impl Parser<'a, ()> {
+    |input: &'a str| match input.strip_prefix('\n') {
+        Some(rest) if !rest.is_empty() && !rest.starts_with('\n') => Ok(((), rest)),
+        _ => Err(Failure {
+            at: input,
+            message: "expected another line".into(),
+        }),
+    }
+}
+
+/// The blank lines between two sections.
+fn blank_lines<'a>() -> impl Parser<'a, ()> {
+    |input: &'a str| {
+        let (newlines, rest) = take_while(input, |c| c == '\n');
+        if newlines.len() >= 2 && !rest.is_empty() {
+            Ok(((), rest))
+        } else {
+            Err(Failure {
+                at: input,
+                message: "expected another section".into(),
+            })
+        }
+    }
+}
+
+/// One `item` per line, up until a blank line or the end of the input.
+pub fn lines<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    separated(item, line_break())
+}
+
+/// One `item` per section of the input, with the sections separated by blank lines.
+pub fn sections<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    separated(item, blank_lines())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point<'a>() -> impl Parser<'a, (i32, i32)> {
+        (int(), literal(","), int()).map(|(x, _, y)| (x, y))
+    }
+
+    #[test]
+    fn parses_formats() {
+        let input = "a: 1,-2\n3,4\n\nb: 5,+6\n";
+        let section = (word(), literal(":"), spaces(), lines(point()))
+            .map(|(name, _, _, points)| (name, points));
+        assert_eq!(
+            parse(input, sections(section)).unwrap(),
+            [("a", vec![(1, -2), (3, 4)]), ("b", vec![(5, 6)])]
+        );
+
+        let flagged = || (opt(literal("!")), uint::<u32>()).map(|(flag, n)| (flag.is_some(), n));
+        assert_eq!(parse("!12", flagged()).unwrap(), (true, 12));
+        assert_eq!(parse("12", flagged()).unwrap(), (false, 12));
+        assert!(parse("-12", uint::<u32>()).is_err());
+        assert!(parse("1,2 3", point()).is_err());
+
+        let digits = || lines(cells(|c| c.to_digit(10), "a digit"));
+        assert_eq!(parse("12\n34", digits()).unwrap(), [[1, 2], [3, 4]]);
+        assert!(parse("12\n\n34", digits()).is_err());
+
+        let pair = (token(), literal(" "), token()).map(|(a, _, b)| (a, b));
+        assert_eq!(parse("32T3K 765", pair).unwrap(), ("32T3K", "765"));
+        assert!(parse(" 765", token()).is_err());
+    }
+
+    #[test]
+    fn reports_where_parsing_failed() {
+        let err = parse("1,2\n3,x\n", lines(point())).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref(),
+            Some(SolveError::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected an integer in \"3,x\""
+        );
+
+        let err = parse("12\n3x", lines(cells(|c| c.to_digit(10), "a digit"))).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected a digit, found 'x' in \"3x\""
+        );
+
+        let err = parse("300", uint::<u8>()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: invalid number \"300\": number too large to fit in target type in \"300\""
+        );
+    }
+}
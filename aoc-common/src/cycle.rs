@@ -0,0 +1,158 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Where a sequence of states starts repeating itself, as found by one of the functions in this
+/// module. The state after `start` steps is the first one that comes round again, `period`
+/// steps later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The smallest number of steps that ends up in the same state as taking `n` steps.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// Finds the cycle with Floyd's tortoise and hare, keeping only a few states around.
+pub fn floyd<S: Clone + PartialEq>(initial: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+
+    // The hare is now a multiple of the period ahead, so both meet again at the start
+    let mut start = 0;
+    let mut tortoise = initial.clone();
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut period = 1;
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Cycle { start, period }
+}
+
+/// Finds the cycle with Brent's algorithm, which keeps only a few states around like [`floyd`]
+/// but needs fewer steps.
+pub fn brent<S: Clone + PartialEq>(initial: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // Start a period apart and walk together until they meet at the start of the cycle
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, period }
+}
+
+/// Finds the cycle by remembering every state, which takes the fewest steps. Returns the states
+/// up until the cycle repeats along with it, so the state after `n` steps is
+/// `states[cycle.reduce(n)]`.
+pub fn find<S: Clone + Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) {
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut states = vec![initial];
+    loop {
+        let next = step(states.last().unwrap());
+        if let Some(&start) = seen.get(&next) {
+            let period = states.len() - start;
+            return (Cycle { start, period }, states);
+        }
+
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+}
+
+/// The state after `n` steps, skipping over the repeats once the states start cycling.
+pub fn nth<S: Clone + Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut states = vec![initial];
+    while states.len() <= n {
+        let next = step(states.last().unwrap());
+        if let Some(&start) = seen.get(&next) {
+            let cycle = Cycle {
+                start,
+                period: states.len() - start,
+            };
+            return states.swap_remove(cycle.reduce(n));
+        }
+
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+
+    states.swap_remove(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 5, 3, 4, 5, ...
+    fn step(n: &u32) -> u32 {
+        if *n == 5 {
+            3
+        } else {
+            n + 1
+        }
+    }
+
+    #[test]
+    fn finds_cycles() {
+        let cycle = Cycle {
+            start: 3,
+            period: 3,
+        };
+        assert_eq!(floyd(&0, step), cycle);
+        assert_eq!(brent(&0, step), cycle);
+
+        let (found, states) = find(0, step);
+        assert_eq!(found, cycle);
+        assert_eq!(states, [0, 1, 2, 3, 4, 5]);
+        assert_eq!(states[cycle.reduce(1_000_000_000)], 4);
+    }
+
+    #[test]
+    fn jumps_ahead() {
+        assert_eq!(nth(0, step, 2), 2);
+        assert_eq!(nth(0, step, 6), 3);
+        assert_eq!(nth(0, step, 1_000_000_000), 4);
+    }
+}
@@ -0,0 +1,42 @@
+use std::fmt::{self, Display};
+
+/// The ways a day can fail on an input, for the failures worth telling apart from bugs. They are
+/// returned through `anyhow` like any other error and can be told apart again by downcasting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The input is not in the expected format.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is valid, but there is no answer for it, e.g. when there is no path through a
+    /// maze.
+    NoSolution(String),
+    /// A search or simulation gave up after this many steps without finding the answer.
+    IterationLimit(usize),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+            SolveError::NoSolution(reason) => write!(f, "no solution: {}", reason),
+            SolveError::IterationLimit(limit) => {
+                write!(f, "gave up after {} iterations", limit)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl SolveError {
+    pub fn no_solution(reason: impl Into<String>) -> SolveError {
+        SolveError::NoSolution(reason.into())
+    }
+}
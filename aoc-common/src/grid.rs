@@ -0,0 +1,649 @@
+use std::{
+    num::TryFromIntError,
+    ops::{Add, Index, IndexMut, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, bail, Result};
+
+/// A rectangular grid, stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T = usize> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Converts the coordinates one at a time, failing if either of them does not fit, so that
+/// e.g. a negative `Point<i32>` never silently wraps around into a huge `Point<usize>`.
+macro_rules! impl_point_try_from {
+    ($($from:ty => $to:ty),* $(,)?) => {
+        $(
+            impl TryFrom<Point<$from>> for Point<$to> {
+                type Error = TryFromIntError;
+
+                fn try_from(val: Point<$from>) -> Result<Self, Self::Error> {
+                    Ok(Point {
+                        x: val.x.try_into()?,
+                        y: val.y.try_into()?,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_point_try_from!(
+    usize => i32,
+    usize => i64,
+    i32 => usize,
+    i64 => usize,
+    i64 => i32,
+);
+
+impl From<Point<i32>> for Point<i64> {
+    fn from(val: Point<i32>) -> Self {
+        Point {
+            x: val.x.into(),
+            y: val.y.into(),
+        }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, scalar: T) -> Point<T> {
+        Point {
+            x: self.x * scalar,
+            y: self.y * scalar,
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        Point {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T> Point<T> {
+    pub fn new(x: T, y: T) -> Point<T> {
+        Point { x, y }
+    }
+}
+
+macro_rules! impl_point_metrics {
+    ($(($t:ty, $unsigned:ty)),*) => {
+        $(
+            impl Point<$t> {
+                /// The distance along the axes, which is unsigned so that it fits even for the
+                /// points furthest apart. It saturates at the largest value instead of
+                /// overflowing.
+                pub fn manhattan_distance(&self, other: &Point<$t>) -> $unsigned {
+                    self.x.abs_diff(other.x).saturating_add(self.y.abs_diff(other.y))
+                }
+
+                pub fn chebyshev_distance(&self, other: &Point<$t>) -> $unsigned {
+                    self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+                }
+
+                /// Wraps the point around onto a `width` by `height` torus, so that e.g. an
+                /// `x` of `-1` becomes `width - 1`.
+                pub fn rem_euclid(&self, width: $t, height: $t) -> Point<$t> {
+                    Point {
+                        x: self.x.rem_euclid(width),
+                        y: self.y.rem_euclid(height),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_point_metrics!((usize, usize), (i32, u32), (i64, u64));
+
+impl Point<usize> {
+    /// Adds a signed offset, returning `None` if either coordinate would become negative.
+    pub fn checked_add<T: Into<i64>>(self, offset: Point<T>) -> Option<Point<usize>> {
+        let x = self
+            .x
+            .checked_add_signed(offset.x.into().try_into().ok()?)?;
+        let y = self
+            .y
+            .checked_add_signed(offset.y.into().try_into().ok()?)?;
+        Some(Point { x, y })
+    }
+
+    /// Moves one step in `direction`, returning `None` if that leaves a `width` by `height`
+    /// grid.
+    pub fn step(
+        self,
+        direction: impl Into<Point<i32>>,
+        width: usize,
+        height: usize,
+    ) -> Option<Point<usize>> {
+        self.checked_add(direction.into())
+            .filter(|point| point.x < width && point.y < height)
+    }
+}
+
+/// One of the four orthogonal directions, with up being towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    pub fn offset(self) -> Point<i32> {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+}
+
+impl From<Direction> for Point<i32> {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = anyhow::Error;
+
+    /// Accepts arrows (`^v<>`), compass points (`NSEW`) and `UDLR`.
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            '^' | 'N' | 'U' => Ok(Direction::Up),
+            '>' | 'E' | 'R' => Ok(Direction::Right),
+            'v' | 'S' | 'D' => Ok(Direction::Down),
+            '<' | 'W' | 'L' => Ok(Direction::Left),
+            _ => Err(anyhow!("Invalid direction: {}", c)),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => c.try_into(),
+            _ => Err(anyhow!("Invalid direction: {}", s)),
+        }
+    }
+}
+
+/// One of the eight directions including the diagonals, with up being towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    fn rotate(self, eighths: usize) -> Dir8 {
+        Dir8::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// Turns 45 degrees anticlockwise.
+    pub fn turn_left(self) -> Dir8 {
+        self.rotate(7)
+    }
+
+    /// Turns 45 degrees clockwise.
+    pub fn turn_right(self) -> Dir8 {
+        self.rotate(1)
+    }
+
+    pub fn opposite(self) -> Dir8 {
+        self.rotate(4)
+    }
+
+    pub fn offset(self) -> Point<i32> {
+        match self {
+            Dir8::Up => Point::new(0, -1),
+            Dir8::UpRight => Point::new(1, -1),
+            Dir8::Right => Point::new(1, 0),
+            Dir8::DownRight => Point::new(1, 1),
+            Dir8::Down => Point::new(0, 1),
+            Dir8::DownLeft => Point::new(-1, 1),
+            Dir8::Left => Point::new(-1, 0),
+            Dir8::UpLeft => Point::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Dir8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Dir8::Up,
+            Direction::Right => Dir8::Right,
+            Direction::Down => Dir8::Down,
+            Direction::Left => Dir8::Left,
+        }
+    }
+}
+
+impl From<Dir8> for Point<i32> {
+    fn from(direction: Dir8) -> Self {
+        direction.offset()
+    }
+}
+
+impl FromStr for Dir8 {
+    type Err = anyhow::Error;
+
+    /// Accepts anything [`Direction`] does, as well as the compass points `NE`, `SE`, `SW`
+    /// and `NW`.
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "NE" => Ok(Dir8::UpRight),
+            "SE" => Ok(Dir8::DownRight),
+            "SW" => Ok(Dir8::DownLeft),
+            "NW" => Ok(Dir8::UpLeft),
+            _ => s.parse::<Direction>().map(Dir8::from),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Builds a grid by calling `cell` for every point, row by row.
+    pub fn from_fn(
+        width: usize,
+        height: usize,
+        mut cell: impl FnMut(Point<usize>) -> T,
+    ) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(&mut cell)
+            .collect();
+
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Parses a grid with one row per line, failing if the rows are not all equally long.
+    pub fn from_str(s: &str, mut parse: impl FnMut(char) -> T) -> Result<Grid<T>> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (i, line) in s.lines().enumerate() {
+            let len = line.chars().count();
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => bail!(
+                    "row {} is {} cells long, but the rows before it are {} cells long",
+                    i + 1,
+                    len,
+                    width
+                ),
+                Some(_) => {}
+            }
+
+            cells.extend(line.chars().map(&mut parse));
+            height += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    /// Builds a grid out of its rows, e.g. as parsed with [`cells`](crate::combinator::cells),
+    /// failing if they are not all equally long.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(i) = rows.iter().position(|row| row.len() != width) {
+            bail!(
+                "row {} is {} cells long, but the rows before it are {} cells long",
+                i + 1,
+                rows[i].len(),
+                width
+            );
+        }
+
+        Ok(Grid {
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+            width,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: &Point<usize>) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    fn index_of(&self, point: &Point<usize>) -> Option<usize> {
+        self.contains(point).then(|| point.y * self.width + point.x)
+    }
+
+    pub fn get(&self, point: &Point<usize>) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: &Point<usize>) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Sets a cell, doing nothing if the point is outside of the grid.
+    pub fn set(&mut self, point: Point<usize>, value: T) {
+        if let Some(cell) = self.get_mut(&point) {
+            *cell = value;
+        }
+    }
+
+    /// Swaps two cells, panicking if either of them is outside of the grid.
+    pub fn swap(&mut self, a: Point<usize>, b: Point<usize>) {
+        let (a, b) = (self.index_of(&a), self.index_of(&b));
+        match (a, b) {
+            (Some(a), Some(b)) => self.cells.swap(a, b),
+            _ => panic!("cannot swap cells outside of the grid"),
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks_exact` panics on a chunk size of 0, which an empty grid would have
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// The cells of a column from top to bottom. Columns are not contiguous, so unlike
+    /// [`Grid::row`] this cannot be a slice.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside of the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell along with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Moves one step from `point` in `direction`, returning `None` if that leaves the grid.
+    pub fn step(
+        &self,
+        point: Point<usize>,
+        direction: impl Into<Point<i32>>,
+    ) -> Option<Point<usize>> {
+        point.step(direction, self.width, self.height)
+    }
+
+    pub fn neighbours_of_point(
+        &self,
+        point: &Point<usize>,
+        include_diagonals: bool,
+    ) -> Vec<Point<usize>> {
+        let mut neighbours = vec![];
+        if point.x > 0 {
+            neighbours.push(Point {
+                x: point.x - 1,
+                y: point.y,
+            });
+            if include_diagonals && point.y > 0 {
+                neighbours.push(Point {
+                    x: point.x - 1,
+                    y: point.y - 1,
+                });
+            }
+            if include_diagonals && point.y + 1 < self.height {
+                neighbours.push(Point {
+                    x: point.x - 1,
+                    y: point.y + 1,
+                });
+            }
+        }
+        if point.x + 1 < self.width {
+            neighbours.push(Point {
+                x: point.x + 1,
+                y: point.y,
+            });
+            if include_diagonals && point.y > 0 {
+                neighbours.push(Point {
+                    x: point.x + 1,
+                    y: point.y - 1,
+                });
+            }
+            if include_diagonals && point.y + 1 < self.height {
+                neighbours.push(Point {
+                    x: point.x + 1,
+                    y: point.y + 1,
+                });
+            }
+        }
+        if point.y > 0 {
+            neighbours.push(Point {
+                x: point.x,
+                y: point.y - 1,
+            });
+        }
+        if point.y + 1 < self.height {
+            neighbours.push(Point {
+                x: point.x,
+                y: point.y + 1,
+            });
+        }
+
+        neighbours
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<usize>) -> &T {
+        self.get(&point).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside of the {}x{} grid",
+                point, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<usize>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(&point)
+            .unwrap_or_else(|| panic!("{:?} is outside of the {}x{} grid", point, width, height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indexes_rows_and_columns() {
+        let mut grid = Grid::from_str("abc\ndef", |c| c).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.get(&Point::new(3, 0)), None);
+
+        grid[Point::new(0, 1)] = 'x';
+        assert_eq!(grid.row(1), ['x', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+    }
+
+    #[test]
+    fn turns_and_steps() {
+        assert_eq!(Direction::Up.turn_right().turn_right(), Direction::Down);
+        assert_eq!(Direction::Left.turn_left(), Direction::Down);
+        assert_eq!(Dir8::UpLeft.turn_right(), Dir8::Up);
+        assert_eq!(Dir8::DownRight.opposite(), Dir8::UpLeft);
+        assert_eq!("v".parse::<Direction>().unwrap(), Direction::Down);
+        assert_eq!("NW".parse::<Dir8>().unwrap(), Dir8::UpLeft);
+        assert!("X".parse::<Direction>().is_err());
+
+        let grid = Grid::new(3, 2, ());
+        assert_eq!(
+            grid.step(Point::new(0, 0), Direction::Right),
+            Some(Point::new(1, 0))
+        );
+        assert_eq!(grid.step(Point::new(0, 0), Dir8::UpRight), None);
+        assert_eq!(grid.step(Point::new(2, 1), Direction::Right), None);
+        assert_eq!(grid.neighbours_of_point(&Point::new(2, 1), true).len(), 3);
+        assert!(Grid::new(1, 0, ())
+            .neighbours_of_point(&Point::new(0, 0), true)
+            .is_empty());
+    }
+
+    #[test]
+    fn converts_and_measures_points() {
+        assert!(Point::<usize>::try_from(Point::new(-1, 0)).is_err());
+        assert_eq!(
+            Point::<usize>::try_from(Point::new(3, 4)),
+            Ok(Point::new(3, 4))
+        );
+        assert_eq!(Point::new(1_usize, 1).checked_add(Point::new(-2, 0)), None);
+        assert_eq!(-(Point::new(1, -2) * 3), Point::new(-3, 6));
+        assert_eq!(Point::new(-7_i64, 12).rem_euclid(5, 5), Point::new(3, 2));
+
+        let (a, b) = (Point::new(1_i32, 5), Point::new(4, 1));
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+        let (a, b) = (Point::new(i32::MIN, 0), Point::new(i32::MAX, i32::MAX));
+        assert_eq!(a.manhattan_distance(&b), u32::MAX);
+        assert_eq!(a.chebyshev_distance(&b), u32::MAX);
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        assert!(Grid::from_str("abc\nde\nfgh", |c| c).is_err());
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+        assert_eq!(
+            Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap()[Point::new(0, 1)],
+            3
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::solution::Part;
+
+/// What the website said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about which way.
+    Wrong,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too high" => Ok(Verdict::TooHigh),
+            "too low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            _ => Err(anyhow!("invalid verdict: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl FromStr for Attempt {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let fields: Vec<&str> = s.split('\t').collect();
+        let [year, day, part, answer, verdict] = fields[..] else {
+            bail!("expected 5 tab separated fields");
+        };
+
+        Ok(Attempt {
+            year: year.parse()?,
+            day: day.parse()?,
+            part: part.parse()?,
+            answer: answer.to_string(),
+            verdict: verdict.parse()?,
+        })
+    }
+}
+
+impl Display for Attempt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}",
+            self.year, self.day, self.part, self.answer, self.verdict
+        )
+    }
+}
+
+/// Every answer submitted so far, kept as a TSV file with one [`Attempt`] per line.
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let attempts = match fs::read_to_string(&path) {
+            Ok(history) => history
+                .lines()
+                .enumerate()
+                .map(|(i, line)| {
+                    line.parse()
+                        .with_context(|| format!("{}:{}: {:?}", path.display(), i + 1, line))
+                })
+                .collect::<Result<_>>()?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read {}", path.display()))
+            }
+        };
+
+        Ok(History { path, attempts })
+    }
+
+    pub fn attempts(&self, year: u16, day: u8, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.year == year && a.day == day && a.part == part)
+    }
+
+    /// Checks an answer against the earlier attempts of its part. Fails if submitting it is
+    /// pointless, i.e. the part is already solved or the very same answer was wrong before, and
+    /// otherwise returns warnings about the answer contradicting earlier too high/too low hints.
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<Vec<String>> {
+        let mut warnings = vec![];
+        for attempt in self.attempts(year, day, part) {
+            if attempt.verdict == Verdict::Correct {
+                bail!("this part was already solved with {}", attempt.answer);
+            }
+            if attempt.answer == answer {
+                bail!(
+                    "{} was already submitted and is {}",
+                    answer,
+                    attempt.verdict
+                );
+            }
+
+            let (Ok(answer), Ok(earlier)) =
+                (answer.parse::<i128>(), attempt.answer.parse::<i128>())
+            else {
+                continue;
+            };
+            match attempt.verdict {
+                Verdict::TooHigh if answer > earlier => warnings.push(format!(
+                    "{} is above {}, which is already too high",
+                    answer, earlier
+                )),
+                Verdict::TooLow if answer < earlier => warnings.push(format!(
+                    "{} is below {}, which is already too low",
+                    answer, earlier
+                )),
+                _ => {}
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    pub fn record(&mut self, attempt: Attempt) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("failed to open {}", self.path.display()))?;
+        writeln!(file, "{}", attempt)
+            .with_context(|| format!("failed to write to {}", self.path.display()))?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(attempts: &[(&str, Verdict)]) -> History {
+        History {
+            path: PathBuf::new(),
+            attempts: attempts
+                .iter()
+                .map(|&(answer, verdict)| Attempt {
+                    year: 2025,
+                    day: 5,
+                    part: Part::One,
+                    answer: answer.to_string(),
+                    verdict,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let history = history(&[("700", Verdict::TooHigh), ("661", Verdict::Correct)]);
+        assert!(history.check(2025, 5, Part::One, "700").is_err());
+        assert!(history.check(2025, 5, Part::One, "650").is_err());
+        assert!(history.check(2025, 5, Part::Two, "700").unwrap().is_empty());
+    }
+
+    #[test]
+    fn warns_outside_of_range() {
+        let history = history(&[("700", Verdict::TooHigh), ("600", Verdict::TooLow)]);
+        assert!(history.check(2025, 5, Part::One, "661").unwrap().is_empty());
+        assert_eq!(history.check(2025, 5, Part::One, "701").unwrap().len(), 1);
+        assert_eq!(history.check(2025, 5, Part::One, "599").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn round_trips_attempts() {
+        let attempt = history(&[("661", Verdict::TooLow)]).attempts.remove(0);
+        assert_eq!(attempt.to_string().parse::<Attempt>().unwrap(), attempt);
+    }
+}
@@ -0,0 +1,21 @@
+pub mod bench;
+pub mod cache;
+pub mod client;
+pub mod combinator;
+pub mod cycle;
+pub mod debug;
+pub mod error;
+pub mod grid;
+pub mod history;
+pub mod math;
+pub mod memo;
+pub mod parse;
+pub mod region;
+pub mod render;
+pub mod scaffold;
+pub mod scan;
+pub mod search;
+pub mod solution;
+pub mod testing;
+pub mod transform;
+pub mod unbounded;
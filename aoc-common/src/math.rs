@@ -0,0 +1,190 @@
+/// The greatest common divisor of `a` and `b`.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// The least common multiple of `a` and `b`, or `None` if it does not fit in a `u64`.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The least common multiple of all the numbers, e.g. when several cycles first line up, or
+/// `None` if it does not fit in a `u64`. This is 1 when there are no numbers.
+pub fn lcm_all(numbers: impl IntoIterator<Item = u64>) -> Option<u64> {
+    numbers.into_iter().try_fold(1, lcm)
+}
+
+/// The greatest common divisor `g` of `a` and `b` along with `x` and `y` such that
+/// `a * x + b * y = g`, as `(g, x, y)`. `g` is never negative.
+///
+/// # Panics
+///
+/// When `a` or `b` is `i128::MIN`, whose negation does not fit in an `i128`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    assert!(
+        a != i128::MIN && b != i128::MIN,
+        "extended_gcd does not support i128::MIN"
+    );
+
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..m` for which `a * x` is 1 modulo `m`, if `a` and `m` are coprime. There is none
+/// when `m` is not positive.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// `a + b` modulo `m` for `a` and `b` in `0..m`, without overflowing even when the sum would.
+fn add_mod(a: i128, b: i128, m: i128) -> i128 {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// `a * b` modulo `m` for `a` and `b` in `0..m`, without overflowing even when the product
+/// would.
+fn mul_mod(a: i128, b: i128, m: i128) -> i128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    let (mut a, mut b, mut product) = (a, b, 0);
+    while b > 0 {
+        if b & 1 == 1 {
+            product = add_mod(product, a, m);
+        }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+
+    product
+}
+
+/// `base` to the power of `exp` modulo `m`, by repeated squaring, or `None` when `m` is 0.
+pub fn mod_pow(base: u64, mut exp: u64, m: u64) -> Option<u64> {
+    if m == 0 {
+        return None;
+    }
+
+    let m = m as u128;
+    let mut base = base as u128 % m;
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+
+    Some(result as u64)
+}
+
+/// Solves `x = a (mod m)` for every `(a, m)` at once by the Chinese remainder theorem, giving
+/// the smallest such `x` that is not negative along with the modulus it repeats with, which is
+/// the least common multiple of the `m`s. The moduli do not have to be coprime, but then there
+/// might not be any `x`. There is no answer either when a modulus is not positive or when their
+/// least common multiple does not fit in an `i128`.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    let (mut x, mut modulus) = (0, 1);
+    for (a, m) in congruences {
+        if m <= 0 {
+            return None;
+        }
+
+        // x + modulus * k = a (mod m) has a solution for k only if the gcd divides the difference
+        let (g, _, _) = extended_gcd(modulus, m);
+        let difference = (a.rem_euclid(m) - x % m).rem_euclid(m);
+        if difference % g != 0 {
+            return None;
+        }
+
+        let reduced = m / g;
+        let k = mul_mod(
+            (difference / g) % reduced,
+            mod_inverse(modulus / g, reduced)?,
+            reduced,
+        );
+        let combined = modulus.checked_mul(reduced)?;
+        x = add_mod(x, mul_mod(modulus % combined, k, combined), combined);
+        modulus = combined;
+    }
+
+    Some((x, modulus))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_divisors_and_multiples() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(lcm_all([4, 6, 10]), Some(60));
+        assert_eq!(lcm_all([]), Some(1));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(4, 10), None);
+        assert_eq!(mod_inverse(3, 0), None);
+        assert_eq!(mod_pow(4, 13, 497), Some(445));
+        assert_eq!(mod_pow(u64::MAX, u64::MAX, u64::MAX - 1), Some(1));
+        assert_eq!(mod_pow(2, 10, 0), None);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // The moduli share a factor of 2
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(0, 4), (1, 6)]), None);
+
+        // A modulus large enough that the products along the way do not fit in an i128
+        let (p, q) = (3, (1 << 100) + 1);
+        let (x, modulus) = crt([(2, p), (7, q)]).unwrap();
+        assert_eq!((x % p, x % q, modulus), (2, 7, p * q));
+
+        // The least common multiple of the moduli does not fit in an i128
+        let big = i128::MAX / 2;
+        assert_eq!(crt([(1, big), (1, big - 2)]), None);
+        assert_eq!(crt([(1, 3), (1, 0)]), None);
+        assert_eq!(
+            crt([(i128::MIN, 7), (i128::MAX, 5)]).map(|(x, _)| x % 7),
+            Some(i128::MIN.rem_euclid(7))
+        );
+    }
+}
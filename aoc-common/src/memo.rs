@@ -0,0 +1,95 @@
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash, RandomState},
+    sync::Mutex,
+};
+
+const SHARDS: usize = 64;
+
+/// A cache of results for memoizing a function, to be created for each run and passed down to
+/// the function (recursive or not) by reference.
+///
+/// It can be shared between threads. The keys are spread over several separately locked maps so
+/// that threads rarely wait on each other.
+pub struct Memo<K, V> {
+    shards: Vec<Mutex<HashMap<K, V>>>,
+    hasher: RandomState,
+}
+
+impl<K: Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo {
+            shards: (0..SHARDS).map(|_| Mutex::new(HashMap::new())).collect(),
+            hasher: RandomState::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Memo<K, V> {
+        Memo::default()
+    }
+
+    /// The cached result for `key`, or else the result of `compute`, which is cached for next
+    /// time. Nothing is locked while `compute` runs, so it is free to recurse through the memo.
+    pub fn get_or_insert_with(&self, key: K, compute: impl FnOnce() -> V) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+
+        let value = compute();
+        self.shard(&key).lock().unwrap().insert(key, value.clone());
+        value
+    }
+
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.shard(key).lock().unwrap().get(key).cloned()
+    }
+
+    /// The number of cached results.
+    pub fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.lock().unwrap().len())
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn shard(&self, key: &K) -> &Mutex<HashMap<K, V>> {
+        &self.shards[self.hasher.hash_one(key) as usize % SHARDS]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    fn fibonacci(memo: &Memo<u64, u64>, n: u64) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.get_or_insert_with(n, || fibonacci(memo, n - 1) + fibonacci(memo, n - 2))
+    }
+
+    #[test]
+    fn memoizes_recursion() {
+        let memo = Memo::new();
+        assert_eq!(fibonacci(&memo, 90), 2880067194370816120);
+        assert_eq!(memo.len(), 89);
+        assert_eq!(memo.get(&10), Some(55));
+
+        // The same memo can be shared between threads
+        thread::scope(|s| {
+            for n in 80..85 {
+                let memo = &memo;
+                s.spawn(move || fibonacci(memo, n));
+            }
+        });
+        assert_eq!(memo.len(), 89);
+    }
+}
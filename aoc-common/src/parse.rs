@@ -0,0 +1,178 @@
+use std::{fmt::Debug, marker::PhantomData, str::FromStr};
+
+use anyhow::{anyhow, bail, Error, Result};
+
+/// Parses every line of the input, pointing at the offending line if one of them is invalid.
+pub fn lines<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    Error: From<T::Err>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.parse()
+                .map_err(|err| Error::from(err).context(format!("line {}: {:?}", i + 1, line)))
+        })
+        .collect()
+}
+
+/// Splits the input into the sections that are separated by blank lines.
+pub fn sections(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split("\n\n")
+        .map(|section| section.trim_matches('\n'))
+        .filter(|section| !section.is_empty())
+}
+
+/// The integers in some text, in order, skipping over whatever is between them. Created with
+/// [`integers`] or [`unsigned_integers`]. A number that does not fit in `T` is an error.
+pub struct Integers<'a, T> {
+    rest: &'a str,
+    signed: bool,
+    separators: Option<&'a str>,
+    after_number: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T> Integers<'a, T> {
+    /// Only allows the characters in `separators` around the integers, with at least one of them
+    /// between each pair, so that anything else in the text is an error instead of being skipped.
+    pub fn separated_by(self, separators: &'a str) -> Self {
+        Integers {
+            separators: Some(separators),
+            ..self
+        }
+    }
+
+    /// Checks the text that was skipped before the next integer, or before the end if there is
+    /// none.
+    fn check_gap(&self, gap: &str, at_end: bool) -> Result<()> {
+        let Some(separators) = self.separators else {
+            return Ok(());
+        };
+        if let Some(c) = gap.chars().find(|c| !separators.contains(*c)) {
+            bail!(
+                "expected {:?} between the integers, found {:?}",
+                separators,
+                c
+            );
+        }
+        if gap.is_empty() && self.after_number && !at_end {
+            bail!("expected {:?} between the integers", separators);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Iterator for Integers<'_, T>
+where
+    T: FromStr,
+    T::Err: Debug,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Result<T>> {
+        let bytes = self.rest.as_bytes();
+        let start = (0..bytes.len()).find(|&i| {
+            bytes[i].is_ascii_digit()
+                || (self.signed
+                    && bytes[i] == b'-'
+                    && bytes.get(i + 1).is_some_and(u8::is_ascii_digit))
+        });
+        let gap = &self.rest[..start.unwrap_or(bytes.len())];
+        if let Err(err) = self.check_gap(gap, start.is_none()) {
+            // there is no telling where the next integer starts after something unexpected
+            self.rest = "";
+            return Some(Err(err));
+        }
+
+        let start = start?;
+        let end = (start + 1..bytes.len())
+            .find(|&i| !bytes[i].is_ascii_digit())
+            .unwrap_or(bytes.len());
+
+        let (number, rest) = (&self.rest[start..end], &self.rest[end..]);
+        self.rest = rest;
+        self.after_number = true;
+        Some(
+            number
+                .parse()
+                .map_err(|err| anyhow!("{:?} is out of range: {:?}", number, err)),
+        )
+    }
+}
+
+/// Every integer in `s`, where a `-` right in front of one is its sign.
+pub fn integers<T>(s: &str) -> Integers<'_, T> {
+    Integers {
+        rest: s,
+        signed: true,
+        separators: None,
+        after_number: false,
+        _marker: PhantomData,
+    }
+}
+
+/// Every run of digits in `s`, treating `-` as a separator like in ranges such as `11-22`.
+pub fn unsigned_integers<T>(s: &str) -> Integers<'_, T> {
+    Integers {
+        rest: s,
+        signed: false,
+        separators: None,
+        after_number: false,
+        _marker: PhantomData,
+    }
+}
+
+/// Exactly `N` integers from `s` (see [`integers`]), for lines that always have the same
+/// numbers in them.
+pub fn integer_array<const N: usize>(s: &str) -> Result<[i64; N]> {
+    let mut numbers = integers(s);
+    let mut array = [0; N];
+    for (found, slot) in array.iter_mut().enumerate() {
+        *slot = numbers
+            .next()
+            .ok_or_else(|| anyhow!("expected {} integers but found {} in {:?}", N, found, s))??;
+    }
+    if numbers.next().is_some() {
+        bail!("expected {} integers but found more in {:?}", N, s);
+    }
+
+    Ok(array)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_integers() {
+        let line = "p=0,4 v=3,-3";
+        let collect = |numbers: Integers<i32>| numbers.collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(collect(integers(line)), [0, 4, 3, -3]);
+        assert_eq!(collect(unsigned_integers(line)), [0, 4, 3, 3]);
+        assert_eq!(
+            collect(unsigned_integers("11-22,95-115")),
+            [11, 22, 95, 115]
+        );
+        assert!(integers::<i64>("- -a").next().is_none());
+        assert!(unsigned_integers::<u8>("1-300").nth(1).unwrap().is_err());
+
+        assert_eq!(integer_array(line).unwrap(), [0, 4, 3, -3]);
+        assert!(integer_array::<3>(line).is_err());
+        assert!(integer_array::<5>(line).is_err());
+
+        let strict = |s| {
+            integers::<i32>(s)
+                .separated_by(" ")
+                .collect::<Result<Vec<_>>>()
+        };
+        assert_eq!(strict(" 1 -2  3 ").unwrap(), [1, -2, 3]);
+        assert!(strict("1 x 3").is_err());
+        assert!(strict("1-2").is_err());
+        assert!(strict("1 2,").is_err());
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::HashSet;
+
+use crate::{
+    grid::{Dir8, Direction, Grid, Point},
+    search::{grid_successors, reachable},
+};
+
+/// A set of orthogonally connected cells of a [`Grid`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub cells: HashSet<Point>,
+}
+
+impl Region {
+    pub fn contains(&self, point: &Point) -> bool {
+        self.cells.contains(point)
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The number of cell edges that border a cell outside of the region.
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|cell| {
+                Direction::ALL
+                    .into_iter()
+                    .filter(|direction| !self.contains_offset(cell, *direction))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// The number of straight sides of the region's outline, including those of any holes in
+    /// it. A polygon has as many sides as corners, so this counts corners instead.
+    pub fn sides(&self) -> usize {
+        let mut corners = 0;
+        for cell in self.cells.iter() {
+            for (a, diagonal, b) in [
+                (Direction::Up, Dir8::UpRight, Direction::Right),
+                (Direction::Right, Dir8::DownRight, Direction::Down),
+                (Direction::Down, Dir8::DownLeft, Direction::Left),
+                (Direction::Left, Dir8::UpLeft, Direction::Up),
+            ] {
+                let a = self.contains_offset(cell, a);
+                let b = self.contains_offset(cell, b);
+                let diagonal = self.contains_offset(cell, diagonal);
+                // Either an outer corner, or an inner one where the region bends around
+                if (!a && !b) || (a && b && !diagonal) {
+                    corners += 1;
+                }
+            }
+        }
+
+        corners
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle around the region.
+    pub fn bounding_box(&self) -> Option<(Point, Point)> {
+        let min_x = self.cells.iter().map(|cell| cell.x).min()?;
+        let min_y = self.cells.iter().map(|cell| cell.y).min()?;
+        let max_x = self.cells.iter().map(|cell| cell.x).max()?;
+        let max_y = self.cells.iter().map(|cell| cell.y).max()?;
+        Some((Point::new(min_x, min_y), Point::new(max_x, max_y)))
+    }
+
+    fn contains_offset(&self, cell: &Point, direction: impl Into<Point<i32>>) -> bool {
+        cell.checked_add(direction.into())
+            .is_some_and(|neighbour| self.contains(&neighbour))
+    }
+}
+
+impl<T> Grid<T> {
+    /// The region around `start`, where neighbouring cells belong together if `connected` holds
+    /// for them.
+    pub fn flood_fill(&self, start: Point, connected: impl Fn(&T, &T) -> bool) -> Region {
+        Region {
+            cells: reachable(start, grid_successors(self, connected)),
+        }
+    }
+
+    /// Splits the whole grid up into regions, see [`Grid::flood_fill`].
+    pub fn regions(&self, connected: impl Fn(&T, &T) -> bool) -> Vec<Region> {
+        let mut visited = Grid::new(self.width(), self.height(), false);
+        let mut regions = vec![];
+        for point in self.points() {
+            if visited[point] {
+                continue;
+            }
+
+            let region = self.flood_fill(point, &connected);
+            for cell in region.cells.iter() {
+                visited[*cell] = true;
+            }
+            regions.push(region);
+        }
+
+        regions
+    }
+}
+
+/// Twice the area of the polygon with the given vertices in order, by the shoelace formula.
+/// Doubling keeps the result whole for polygons on a grid.
+pub fn double_area(vertices: &[Point<i64>]) -> i64 {
+    let n = vertices.len();
+    (0..n)
+        .map(|i| {
+            let (a, b) = (vertices[i], vertices[(i + 1) % n]);
+            a.x * b.y - b.x * a.y
+        })
+        .sum::<i64>()
+        .abs()
+}
+
+/// The number of cells strictly inside a closed loop through cell centres, given the corners of
+/// the loop in order (listing every cell of the loop works too). The edges between the corners
+/// have to be horizontal or vertical.
+///
+/// This is Pick's theorem, `A = i + b / 2 - 1`, solved for the interior `i`.
+pub fn cells_inside(vertices: &[Point<i64>]) -> i64 {
+    let n = vertices.len();
+    let boundary: u64 = (0..n)
+        .map(|i| vertices[i].manhattan_distance(&vertices[(i + 1) % n]))
+        .sum();
+
+    // A loop too long for an i64 encloses an area that does not fit in one either
+    (double_area(vertices) - boundary as i64 + 2) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_regions() {
+        let grid = Grid::from_str("AAAA\nBBCD\nBBCC\nEEEC", |c| c).unwrap();
+        let regions = grid.regions(|a, b| a == b);
+        assert_eq!(regions.len(), 5);
+
+        let c = regions
+            .iter()
+            .find(|region| region.contains(&Point::new(2, 1)))
+            .unwrap();
+        assert_eq!((c.area(), c.perimeter(), c.sides()), (4, 10, 8));
+        assert_eq!(c.bounding_box(), Some((Point::new(2, 1), Point::new(3, 3))));
+
+        // A region with a hole in it has sides on the inside too
+        let grid = Grid::from_str("OOO\nOXO\nOOO", |c| c).unwrap();
+        let ring = grid.flood_fill(Point::new(0, 0), |a, b| a == b);
+        assert_eq!((ring.area(), ring.perimeter(), ring.sides()), (8, 16, 8));
+    }
+
+    #[test]
+    fn counts_cells_inside_loops() {
+        let square = [(0, 0), (4, 0), (4, 4), (0, 4)].map(|(x, y)| Point::new(x, y));
+        assert_eq!(double_area(&square), 32);
+        assert_eq!(cells_inside(&square), 9);
+    }
+}
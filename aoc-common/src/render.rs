@@ -0,0 +1,193 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::grid::{Grid, Point};
+
+/// The color of a pixel as red, green and blue.
+pub type Rgb = [u8; 3];
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A grid drawn as text with one char per cell, see [`Grid::render`].
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    cell: Box<dyn Fn(&T) -> char + 'a>,
+    overlay: HashMap<Point, char>,
+}
+
+impl<'a, T> Render<'a, T> {
+    /// Draws `c` over the given points, on top of the cells and any earlier highlights.
+    pub fn highlight(mut self, points: impl IntoIterator<Item = Point>, c: char) -> Self {
+        self.overlay
+            .extend(points.into_iter().map(|point| (point, c)));
+        self
+    }
+}
+
+impl<T> Display for Render<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (point, cell) in self.grid.iter() {
+            let c = match self.overlay.get(&point) {
+                Some(c) => *c,
+                None => (self.cell)(cell),
+            };
+            write!(f, "{}", c)?;
+            if point.x == self.grid.width() - 1 {
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Grid<T> {
+    /// Draws the grid as text, turning each cell into a char with `cell`.
+    pub fn render<'a>(&'a self, cell: impl Fn(&T) -> char + 'a) -> Render<'a, T> {
+        Render {
+            grid: self,
+            cell: Box::new(cell),
+            overlay: HashMap::new(),
+        }
+    }
+
+    /// Turns the grid into an image with one pixel per cell. Points can be highlighted by
+    /// setting their pixels afterwards.
+    pub fn to_image(&self, color: impl Fn(&T) -> Rgb) -> Grid<Rgb> {
+        let mut image = Grid::new(self.width(), self.height(), [0; 3]);
+        for (point, cell) in self.iter() {
+            image[point] = color(cell);
+        }
+
+        image
+    }
+}
+
+/// Saves an image as a PPM or PNG file depending on the extension of `path`, drawing each pixel
+/// as a `scale` by `scale` square so that small grids are still visible.
+pub fn save_image(image: &Grid<Rgb>, path: &Path, scale: usize) -> Result<()> {
+    let extension = path.extension().and_then(|ext| ext.to_str());
+    let data = encode_image(image, extension, scale)
+        .with_context(|| format!("failed to save {:?}", path))?;
+    fs::write(path, data).with_context(|| format!("failed to save {:?}", path))
+}
+
+fn encode_image(image: &Grid<Rgb>, extension: Option<&str>, scale: usize) -> Result<Vec<u8>> {
+    let (width, height) = (image.width() * scale, image.height() * scale);
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for row in image.rows() {
+        for _ in 0..scale {
+            for pixel in row {
+                for _ in 0..scale {
+                    pixels.extend_from_slice(pixel);
+                }
+            }
+        }
+    }
+
+    let mut data = vec![];
+    match extension {
+        Some("ppm") => {
+            write!(data, "P6\n{} {}\n255\n", width, height)?;
+            data.extend(pixels);
+        }
+        Some("png") => {
+            let mut encoder = png::Encoder::new(&mut data, width as u32, height as u32);
+            encoder.set_color(png::ColorType::Rgb);
+            encoder.set_depth(png::BitDepth::Eight);
+            encoder.write_header()?.write_image_data(&pixels)?;
+        }
+        _ => bail!("images can only be saved as .ppm or .png"),
+    }
+
+    Ok(data)
+}
+
+/// Saves the steps of a simulation as numbered images, `frame_00000.png` and so on, which can
+/// then be flipped through or stitched into a video.
+pub struct Frames {
+    dir: PathBuf,
+    extension: &'static str,
+    scale: usize,
+    next: usize,
+}
+
+impl Frames {
+    /// Creates `dir` if needed. `extension` is either `"ppm"` or `"png"`.
+    pub fn new(dir: impl Into<PathBuf>, extension: &'static str, scale: usize) -> Result<Frames> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).with_context(|| format!("failed to create {:?}", dir))?;
+        Ok(Frames {
+            dir,
+            extension,
+            scale,
+            next: 0,
+        })
+    }
+
+    /// Saves the next frame, returning where it was saved.
+    pub fn save(&mut self, image: &Grid<Rgb>) -> Result<PathBuf> {
+        let path = self
+            .dir
+            .join(format!("frame_{:05}.{}", self.next, self.extension));
+        save_image(image, &path, self.scale)?;
+        self.next += 1;
+
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_text() {
+        let grid = Grid::from_str("123\n456", |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let render = grid
+            .render(|n| if n % 2 == 0 { 'E' } else { 'O' })
+            .highlight([Point::new(0, 0), Point::new(1, 1)], '#');
+        assert_eq!(render.to_string(), "#EO\nE#E\n");
+    }
+
+    #[test]
+    fn encodes_images() {
+        let image = Grid::from_str("#.\n.#", |c| c)
+            .unwrap()
+            .to_image(|c| match c {
+                '#' => [255, 255, 255],
+                _ => [0, 0, 0],
+            });
+
+        let header = b"P6\n4 4\n255\n";
+        let ppm = encode_image(&image, Some("ppm"), 2).unwrap();
+        assert!(ppm.starts_with(header));
+        assert_eq!(ppm.len(), header.len() + 4 * 4 * 3);
+        assert_eq!(ppm[header.len()..header.len() + 6], [255; 6]);
+
+        let png = encode_image(&image, Some("png"), 1).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+
+        assert!(encode_image(&image, Some("gif"), 1).is_err());
+    }
+}
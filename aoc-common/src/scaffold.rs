@@ -0,0 +1,200 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::solution::Year;
+
+const MOD_TEMPLATE: &str = r#"use std::fmt::Display;
+
+use anyhow::Result;
+use aoc_common::solution::{self, Solution};
+
+pub struct Day{NN};
+
+impl Solution for Day{NN} {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &Self::Input) -> Result<impl Display> {
+        solution::unsolved()
+    }
+
+    fn part2(_input: &Self::Input) -> Result<impl Display> {
+        solution::unsolved()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_common::day_tests!(
+        crate::YEAR,
+        {N},
+        #[ignore = "TODO: fill in the answers to the sample"]
+        sample: [_, _],
+    );
+}
+"#;
+
+const BIN_TEMPLATE: &str = r#"use anyhow::Result;
+
+fn main() -> Result<()> {
+    aoc_common::solution::main_for_day(&aoc{YEAR}::YEAR, {N})
+}
+"#;
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{YEAR}", &year.to_string())
+        .replace("{NN}", &format!("{:02}", day))
+        .replace("{N}", &day.to_string())
+}
+
+/// Adds a new day to a year: its module with an unsolved [`Solution`](crate::solution::Solution),
+/// binary and empty inputs, registered in the year's `lib.rs` and `Cargo.toml`. Returns the
+/// files that were created or changed.
+pub fn new_day(year: &Year, day: u8) -> Result<Vec<PathBuf>> {
+    let src_dir = Path::new(year.dir);
+    let crate_dir = src_dir
+        .parent()
+        .ok_or_else(|| anyhow!("{} is not in a crate", src_dir.display()))?;
+    let day_dir = year.day_dir(day);
+    if day_dir.exists() {
+        bail!("day {} already exists in {}", day, day_dir.display());
+    }
+
+    let lib = src_dir.join("lib.rs");
+    let manifest = crate_dir.join("Cargo.toml");
+    // work out the changes to the existing files first, so that nothing is left half done when
+    // they cannot be understood
+    let new_lib = register_module(&read(&lib)?, day)?;
+    let new_manifest = register_bin(&read(&manifest)?, year.year, day);
+
+    fs::create_dir_all(&day_dir)
+        .with_context(|| format!("failed to create {}", day_dir.display()))?;
+    let files = [
+        (day_dir.join("mod.rs"), render(MOD_TEMPLATE, year.year, day)),
+        (day_dir.join("bin.rs"), render(BIN_TEMPLATE, year.year, day)),
+        (day_dir.join("sample_input.txt"), String::new()),
+        (day_dir.join("input.txt"), String::new()),
+        (lib, new_lib),
+        (manifest, new_manifest),
+    ];
+    for (path, contents) in &files {
+        fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))
+}
+
+/// Inserts the day into the sorted `pub mod dayNN;` declarations and `Day::new` list of a
+/// `lib.rs`.
+fn register_module(lib: &str, day: u8) -> Result<String> {
+    let mut lines: Vec<String> = lib.lines().map(String::from).collect();
+    let entries = [
+        ("pub mod day", format!("pub mod day{:02};", day)),
+        (
+            "Day::new::<day",
+            format!("        Day::new::<day{:02}::Day{:02}>({}),", day, day, day),
+        ),
+    ];
+
+    for (prefix, entry) in entries {
+        let existing: Vec<usize> = (0..lines.len())
+            .filter(|&i| lines[i].trim_start().starts_with(prefix))
+            .collect();
+        let Some(&last) = existing.last() else {
+            bail!("could not find where to add {:?} to lib.rs", entry.trim());
+        };
+        let at = existing
+            .into_iter()
+            .find(|&i| lines[i].trim() > entry.trim())
+            .unwrap_or(last + 1);
+        lines.insert(at, entry);
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds a `[[bin]]` target for the day to a `Cargo.toml`, before the targets of later days.
+fn register_bin(manifest: &str, year: u16, day: u8) -> String {
+    let name = format!("aoc{}-day{:02}", year, day);
+    let bin = format!(
+        "[[bin]]\nname = \"{}\"\npath = \"src/day{:02}/bin.rs\"\n",
+        name, day
+    );
+
+    let later = manifest
+        .match_indices("[[bin]]\nname = \"")
+        .find(|(i, header)| {
+            let name_start = i + header.len();
+            manifest[name_start..]
+                .split('"')
+                .next()
+                .is_some_and(|other| other.len() == name.len() && other > name.as_str())
+        });
+    match later {
+        Some((i, _)) => format!("{}{}\n{}", &manifest[..i], bin, &manifest[i..]),
+        None => format!("{}\n\n{}", manifest.trim_end(), bin),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "pub mod day01;
+pub mod day03;
+
+pub const YEAR: Year = Year {
+    days: &[
+        Day::new::<day01::Day01>(1),
+        Day::new::<day03::Day03>(3),
+    ],
+};
+";
+
+    #[test]
+    fn registers_modules_in_order() {
+        let lib = register_module(LIB, 2).unwrap();
+        assert!(lib.starts_with("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(lib.contains(
+            "        Day::new::<day01::Day01>(1),
+        Day::new::<day02::Day02>(2),
+        Day::new::<day03::Day03>(3),"
+        ));
+
+        let lib = register_module(LIB, 4).unwrap();
+        assert!(lib.contains("pub mod day03;\npub mod day04;\n\n"));
+        assert!(lib.contains("(3),\n        Day::new::<day04::Day04>(4),\n    ],"));
+    }
+
+    #[test]
+    fn registers_bins_in_order() {
+        let manifest = "[package]\nname = \"aoc2025\"\n\n[[bin]]\nname = \"aoc2025-day03\"\npath = \"src/day03/bin.rs\"\n";
+        let bin = |day: u8| {
+            format!(
+                "[[bin]]\nname = \"aoc2025-day{0:02}\"\npath = \"src/day{0:02}/bin.rs\"\n",
+                day
+            )
+        };
+
+        assert_eq!(
+            register_bin(manifest, 2025, 2),
+            format!("[package]\nname = \"aoc2025\"\n\n{}\n{}", bin(2), bin(3))
+        );
+        assert_eq!(
+            register_bin(manifest, 2025, 4),
+            format!("[package]\nname = \"aoc2025\"\n\n{}\n{}", bin(3), bin(4))
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use std::{any::type_name, fmt::Debug, str::FromStr};
+
+use anyhow::{anyhow, Context, Result};
+
+/// The number of `{}` placeholders in a [`scan!`](crate::scan) template. Called in a constant so
+/// that a bad template fails to compile: every `{` has to be part of a `{}`, and two placeholders
+/// cannot be next to each other as there would be no telling where one value ends.
+pub const fn placeholders(template: &str) -> usize {
+    let bytes = template.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'{' => {
+                if i + 1 >= bytes.len() || bytes[i + 1] != b'}' {
+                    panic!("a `{{` in a scan template has to be followed by `}}`");
+                }
+                if i + 2 < bytes.len() && bytes[i + 2] == b'{' {
+                    panic!("placeholders in a scan template have to be separated by some text");
+                }
+                count += 1;
+                i += 2;
+            }
+            b'}' => panic!("a `}}` in a scan template has to follow `{{`"),
+            _ => i += 1,
+        }
+    }
+
+    count
+}
+
+/// The text matched by each placeholder of a template, see [`scan!`](crate::scan).
+pub struct Fields<'a> {
+    fields: std::vec::IntoIter<&'a str>,
+    input: &'a str,
+}
+
+impl<'a> Fields<'a> {
+    /// Matches `input` against `template`, where each `{}` takes up everything up until the text
+    /// that follows it.
+    pub fn new(input: &'a str, template: &str) -> Result<Fields<'a>> {
+        let mismatch = |text: &str| anyhow!("expected {:?} in {:?}", text, input);
+
+        let mut pieces = template.split("{}");
+        let first = pieces.next().unwrap_or_default();
+        let mut rest = input.strip_prefix(first).ok_or_else(|| mismatch(first))?;
+        let mut fields = vec![];
+        for piece in pieces {
+            let end = if piece.is_empty() {
+                rest.len()
+            } else {
+                rest.find(piece).ok_or_else(|| mismatch(piece))?
+            };
+            fields.push(&rest[..end]);
+            rest = &rest[end + piece.len()..];
+        }
+
+        if !rest.is_empty() {
+            return Err(anyhow!("unexpected {:?} at the end of {:?}", rest, input));
+        }
+
+        Ok(Fields {
+            fields: fields.into_iter(),
+            input,
+        })
+    }
+
+    /// Parses the next field, ignoring any whitespace around it.
+    pub fn parse_next<T>(&mut self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Debug,
+    {
+        let field = self.fields.next().context("ran out of fields")?;
+        field.trim().parse().map_err(|err| {
+            anyhow!(
+                "{:?} is not a valid {} in {:?}: {:?}",
+                field,
+                type_name::<T>(),
+                self.input,
+                err
+            )
+        })
+    }
+}
+
+/// Pulls values out of a string with a fixed format, returning them as a tuple. Each `{}` in the
+/// template stands for a value of the matching type, and the rest of the template has to match
+/// the string exactly.
+///
+/// ```
+/// let (x, y, dx, dy) = aoc_common::scan!("p=0,4 v=3,-3", "p={},{} v={},{}" => i64, i64, i64, i64)?;
+/// assert_eq!((x, y, dx, dy), (0, 4, 3, -3));
+/// # anyhow::Ok(())
+/// ```
+///
+/// Templates are checked when compiling, so one with the wrong number of placeholders does not
+/// build.
+#[macro_export]
+macro_rules! scan {
+    ($input:expr, $template:literal => $($ty:ty),+ $(,)?) => {{
+        const _: () = assert!(
+            $crate::scan::placeholders($template) == [$(stringify!($ty)),+].len(),
+            "the number of types does not match the number of placeholders in the template"
+        );
+        $crate::scan::Fields::new($input, $template)
+            .and_then(|mut fields| Ok(($(fields.parse_next::<$ty>()?,)+)))
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scans_values() {
+        let (min, max, letter, password) =
+            crate::scan!("1-3 a: abcde", "{}-{} {}: {}" => usize, usize, char, String).unwrap();
+        assert_eq!((min, max, letter, password.as_str()), (1, 3, 'a', "abcde"));
+
+        let (x, vx) = crate::scan!("19 @  -2", "{}@{}" => i64, i64).unwrap();
+        assert_eq!((x, vx), (19, -2));
+
+        assert!(crate::scan!("p=0,4", "p={},{} v" => i64, i64).is_err());
+        assert!(crate::scan!("p=0,x", "p={},{}" => i64, i64).is_err());
+        assert!(crate::scan!("p=0,4!", "p={},{}" => i64, i64).is_err());
+
+        assert_eq!(placeholders("{} to {}"), 2);
+    }
+}
@@ -0,0 +1,366 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::grid::{Grid, Point};
+
+/// Successors for searching orthogonally over `grid`, where a step from one cell to the next is
+/// only allowed if `passable(from, to)` holds.
+pub fn grid_successors<'a, T>(
+    grid: &'a Grid<T>,
+    passable: impl Fn(&T, &T) -> bool + 'a,
+) -> impl FnMut(&Point) -> Vec<Point> + 'a {
+    move |point| {
+        grid.neighbours_of_point(point, false)
+            .into_iter()
+            .filter(|neighbour| passable(&grid[*point], &grid[*neighbour]))
+            .collect()
+    }
+}
+
+/// Every node that can be reached from `start`, including `start` itself.
+pub fn reachable<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> HashSet<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        for next in successors(&node) {
+            if seen.insert(next.clone()) {
+                stack.push(next);
+            }
+        }
+    }
+
+    seen
+}
+
+/// The number of steps to every node that can be reached from `start`.
+pub fn bfs_distances<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in successors(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+/// The path with the fewest steps from `start` to a node for which `success` holds, including
+/// both ends.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::from([(start.clone(), None)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if success(&node) {
+            return Some(reconstruct_path(&parents, node));
+        }
+
+        for next in successors(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// The cheapest path from `start` to a node for which `success` holds along with its cost.
+/// `successors` gives each neighbouring node along with the cost of moving there.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), success)
+}
+
+/// Like [`dijkstra`], but guided by a `heuristic` that must never overestimate the remaining
+/// cost to reach the goal.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // The best known cost of every node and the node it was reached from
+    let mut best: HashMap<N, (C, Option<N>)> =
+        HashMap::from([(start.clone(), (C::default(), None))]);
+    // Nodes are compared by index so that `N` does not have to be `Ord`
+    let mut nodes = vec![start];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&nodes[0]), C::default(), 0))]);
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        if cost > best[&node].0 {
+            continue;
+        }
+        if success(&node) {
+            let parents = best.into_iter().map(|(n, (_, p))| (n, p)).collect();
+            return Some((reconstruct_path(&parents, node), cost));
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if best.get(&next).is_some_and(|(c, _)| *c <= next_cost) {
+                continue;
+            }
+
+            best.insert(next.clone(), (next_cost, Some(node.clone())));
+            heap.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                nodes.len(),
+            )));
+            nodes.push(next);
+        }
+    }
+
+    None
+}
+
+/// The cheapest cost to every node that can be reached from `start`.
+pub fn dijkstra_all<N, C, I>(start: N, mut successors: impl FnMut(&N) -> I) -> HashMap<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut nodes = vec![start];
+    let mut heap = BinaryHeap::from([Reverse((C::default(), 0))]);
+
+    while let Some(Reverse((cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        if cost > costs[&node] {
+            continue;
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_some_and(|c| *c <= next_cost) {
+                continue;
+            }
+
+            costs.insert(next.clone(), next_cost);
+            heap.push(Reverse((next_cost, nodes.len())));
+            nodes.push(next);
+        }
+    }
+
+    costs
+}
+
+/// Every cheapest path from `start` to a node for which `success` holds, along with their
+/// shared cost. There can be exponentially many of these, so this is only meant for graphs
+/// where the cheapest paths mostly overlap.
+///
+/// # Panics
+///
+/// Every step must cost more than nothing. A free step back to an earlier node would make it a
+/// predecessor of itself, and the paths through it would never end.
+pub fn all_shortest_paths<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<Vec<N>>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // Every node a node can be reached from at its cheapest cost
+    let mut best: HashMap<N, (C, Vec<N>)> =
+        HashMap::from([(start.clone(), (C::default(), vec![]))]);
+    let mut nodes = vec![start];
+    let mut heap = BinaryHeap::from([Reverse((C::default(), 0))]);
+    let mut goals = vec![];
+    let mut goal_cost = None;
+
+    while let Some(Reverse((cost, index))) = heap.pop() {
+        if goal_cost.is_some_and(|goal_cost| cost > goal_cost) {
+            break;
+        }
+        let node = nodes[index].clone();
+        if cost > best[&node].0 {
+            continue;
+        }
+        if success(&node) {
+            goal_cost = Some(cost);
+            goals.push(node);
+            continue;
+        }
+
+        for (next, step) in successors(&node) {
+            assert!(
+                step > C::default(),
+                "all_shortest_paths needs every step to have a positive cost"
+            );
+            let next_cost = cost + step;
+            match best.get_mut(&next) {
+                Some((c, _)) if *c < next_cost => continue,
+                Some((c, parents)) if *c == next_cost => {
+                    parents.push(node.clone());
+                    continue;
+                }
+                _ => {}
+            }
+
+            best.insert(next.clone(), (next_cost, vec![node.clone()]));
+            heap.push(Reverse((next_cost, nodes.len())));
+            nodes.push(next);
+        }
+    }
+
+    let mut paths = vec![];
+    for goal in goals {
+        let mut stack = vec![vec![goal]];
+        while let Some(path) = stack.pop() {
+            let parents = &best[path.last().unwrap()].1;
+            if parents.is_empty() {
+                paths.push(path.into_iter().rev().collect());
+                continue;
+            }
+            for parent in parents {
+                let mut path = path.clone();
+                path.push(parent.clone());
+                stack.push(path);
+            }
+        }
+    }
+
+    Some((paths, goal_cost?))
+}
+
+fn reconstruct_path<N: Eq + Hash + Clone>(parents: &HashMap<N, Option<N>>, end: N) -> Vec<N> {
+    let mut path = vec![end];
+    while let Some(Some(parent)) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "\
+..#....
+..#.##.
+.....#.
+.###.#.
+.....#.";
+
+    fn maze() -> Grid<char> {
+        Grid::from_str(MAZE, |c| c).unwrap()
+    }
+
+    #[test]
+    fn finds_shortest_paths() {
+        let grid = maze();
+        let goal = Point::new(6, 4);
+
+        let path = bfs(
+            Point::new(0, 0),
+            grid_successors(&grid, |_, to| *to != '#'),
+            |p| *p == goal,
+        )
+        .unwrap();
+        assert_eq!(path.len() - 1, 14);
+        assert_eq!(path.first(), Some(&Point::new(0, 0)));
+        assert_eq!(path.last(), Some(&goal));
+
+        let mut successors = grid_successors(&grid, |_, to| *to != '#');
+        let weighted = |p: &Point| {
+            successors(p)
+                .into_iter()
+                .map(|n| (n, 1))
+                .collect::<Vec<_>>()
+        };
+        let (_, cost) = astar(
+            Point::new(0, 0),
+            weighted,
+            |p| p.manhattan_distance(&goal),
+            |p| *p == goal,
+        )
+        .unwrap();
+        assert_eq!(cost, 14);
+
+        let distances = bfs_distances(Point::new(0, 0), grid_successors(&grid, |_, to| *to != '#'));
+        assert_eq!(distances[&goal], 14);
+        assert_eq!(
+            distances.len(),
+            grid.iter().filter(|(_, c)| **c != '#').count()
+        );
+    }
+
+    #[test]
+    fn finds_every_cheapest_path() {
+        // Two ways around a square, each costing 2
+        let successors = |n: &u8| match n {
+            0 => vec![(1, 1), (2, 1), (3, 5)],
+            1 | 2 => vec![(3, 1)],
+            _ => vec![],
+        };
+
+        let (paths, cost) = all_shortest_paths(0, successors, |n| *n == 3).unwrap();
+        assert_eq!(cost, 2);
+        assert_eq!(paths.len(), 2);
+        assert!(paths.contains(&vec![0, 1, 3]) && paths.contains(&vec![0, 2, 3]));
+
+        assert_eq!(dijkstra(0, successors, |n| *n == 3).unwrap().1, 2);
+        assert_eq!(dijkstra_all(0, successors)[&3], 2);
+        assert_eq!(
+            reachable(1, |n| successors(n).into_iter().map(|(n, _)| n)).len(),
+            2
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "positive cost")]
+    fn rejects_free_steps() {
+        // Going back and forth between 0 and 1 costs nothing, so there is no end to the paths
+        let successors = |n: &u8| match n {
+            0 => vec![(1, 0), (2, 1)],
+            1 => vec![(0, 0)],
+            _ => vec![],
+        };
+        all_shortest_paths(0, successors, |n| *n == 2);
+    }
+}
@@ -0,0 +1,568 @@
+use std::{
+    convert::Infallible,
+    fmt::Display,
+    fs,
+    hint::black_box,
+    io,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Args, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+
+use crate::{
+    bench::{self, Report, Timings},
+    cache::Cache,
+    client::{Client, Response},
+    debug,
+    history::{Attempt, History, Verdict},
+    scaffold,
+};
+
+/// A single day's puzzle: how to turn the raw input into something both parts can work on, and
+/// how to solve each part from that.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Parses the sample, for days where it differs from the real input in a way that is only
+    /// given in the puzzle text, like the size of a grid.
+    fn parse_sample(input: &str) -> Result<Self::Input> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display>;
+    fn part2(input: &Self::Input) -> Result<impl Display>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(anyhow!("part must be either 1 or 2, got {}", s)),
+        }
+    }
+}
+
+/// The answer of a part that has not been solved yet.
+pub fn unsolved() -> Result<&'static str> {
+    Err(anyhow!("this part has not been solved yet"))
+}
+
+/// The answer of a part, along with how long it took to solve (not counting parsing the input).
+pub struct Answer {
+    pub part: Part,
+    pub answer: Result<String>,
+    pub elapsed: Duration,
+}
+
+/// The answers of the requested parts, in the order they were requested. A part that fails does
+/// not keep the other part from being solved.
+pub type Answers = Vec<Answer>;
+
+/// A type-erased [`Solution`] so that every day of the year can live in one list.
+pub struct Day {
+    pub day: u8,
+    solve: fn(&str, &InputSource, &[Part]) -> Result<Answers>,
+    bench: fn(&str) -> Result<Timings>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(day: u8) -> Day {
+        Day {
+            day,
+            solve: solve::<S>,
+            bench: bench::<S>,
+        }
+    }
+
+    /// Solves the parts on the contents of `input`, which is read by the caller.
+    pub fn solve(&self, contents: &str, input: &InputSource, parts: &[Part]) -> Result<Answers> {
+        (self.solve)(contents, input, parts)
+    }
+
+    /// Times parsing the input and solving both parts once.
+    pub fn bench(&self, input: &str) -> Result<Timings> {
+        (self.bench)(input)
+    }
+}
+
+fn solve<S: Solution>(contents: &str, input: &InputSource, parts: &[Part]) -> Result<Answers> {
+    let input = match input {
+        InputSource::Sample => S::parse_sample(contents)?,
+        _ => S::parse(contents)?,
+    };
+
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&input).map(|answer| answer.to_string()),
+                Part::Two => S::part2(&input).map(|answer| answer.to_string()),
+            };
+            Answer {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect())
+}
+
+fn bench<S: Solution>(input: &str) -> Result<Timings> {
+    let start = Instant::now();
+    let input = S::parse(black_box(input))?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let part1 = black_box(S::part1(&input)).is_ok().then(|| start.elapsed());
+    let start = Instant::now();
+    let part2 = black_box(S::part2(&input)).is_ok().then(|| start.elapsed());
+
+    Ok(Timings {
+        parse,
+        parts: [part1, part2],
+    })
+}
+
+/// Every solved day of a year.
+pub struct Year {
+    pub year: u16,
+    /// The directory holding a `dayNN` directory with the inputs of every day.
+    pub dir: &'static str,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub fn day(&self, day: u8) -> Result<&Day> {
+        self.days
+            .iter()
+            .find(|d| d.day == day)
+            .ok_or_else(|| anyhow!("day {} has not been solved", day))
+    }
+
+    pub fn day_dir(&self, day: u8) -> PathBuf {
+        Path::new(self.dir).join(format!("day{:02}", day))
+    }
+}
+
+/// Which input a day is run on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The `input.txt` of the day unless it is empty, or else its input downloaded into the
+    /// [`Cache`] by `fetch`.
+    Real,
+    /// The `sample_input.txt` of the day, or `sample_input_<part>.txt` when the sample differs
+    /// between the parts.
+    Sample,
+    File(PathBuf),
+    Stdin,
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "real" => InputSource::Real,
+            "sample" => InputSource::Sample,
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        })
+    }
+}
+
+impl InputSource {
+    /// The file to read the input of `part` from, or `None` if it has to be read from stdin.
+    pub fn path(&self, year: &Year, day: u8, part: Part) -> Option<PathBuf> {
+        let day_dir = year.day_dir(day);
+        match self {
+            InputSource::Real => {
+                // an empty `input.txt` is the stub left by `new`, which should not hide the
+                // downloaded input
+                let input = day_dir.join("input.txt");
+                if fs::metadata(&input).is_ok_and(|metadata| metadata.len() > 0) {
+                    Some(input)
+                } else {
+                    Some(Cache::from_env().input_path(year.year, day))
+                }
+            }
+            InputSource::Sample => {
+                let part_sample = day_dir.join(format!("sample_input_{}.txt", part));
+                if part_sample.exists() {
+                    Some(part_sample)
+                } else {
+                    Some(day_dir.join("sample_input.txt"))
+                }
+            }
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+}
+
+pub(crate) fn read_input(path: Option<&Path>) -> Result<String> {
+    match path {
+        Some(path) => fs::read_to_string(path)
+            .with_context(|| format!("failed to read the input from {}", path.display())),
+        None => io::read_to_string(io::stdin()).context("failed to read the input from stdin"),
+    }
+}
+
+/// Solves the requested parts of a day, reading and parsing the input only once when both parts
+/// run on the same input.
+pub fn solve_day(year: &Year, day: &Day, input: &InputSource, parts: &[Part]) -> Result<Answers> {
+    let paths: Vec<Option<PathBuf>> = parts
+        .iter()
+        .map(|&part| input.path(year, day.day, part))
+        .collect();
+
+    if let Some(first) = paths.first() {
+        if paths.iter().all(|path| path == first) {
+            let contents = read_input(first.as_deref())?;
+            return day.solve(&contents, input, parts);
+        }
+    }
+
+    let mut answers = vec![];
+    for (&part, path) in parts.iter().zip(paths) {
+        let contents = read_input(path.as_deref())?;
+        answers.extend(day.solve(&contents, input, &[part])?);
+    }
+
+    Ok(answers)
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// The input to run on: `real`, `sample`, the path to a file or `-` for stdin
+    #[arg(short, long, global = true, default_value = "real")]
+    input: InputSource,
+}
+
+/// How the answers are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// `Part 1: <answer>`, under a `Day NN` heading when running a whole year
+    Text,
+    /// One JSON object per answer, with the year, day, part, answer and elapsed nanoseconds
+    Json,
+    /// One line per answer: year, day, part, answer and elapsed nanoseconds, separated by tabs
+    Tsv,
+}
+
+#[derive(Serialize)]
+struct JsonAnswer<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    elapsed_ns: u64,
+}
+
+#[derive(Args)]
+struct OutputArgs {
+    /// How to print the answers. Parts that fail are always reported on stderr
+    #[arg(short, long, global = true, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Draw the debug visuals of the days that have them, on stderr
+    #[arg(long, global = true)]
+    debug: bool,
+}
+
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    input: InputArgs,
+    #[command(flatten)]
+    output: OutputArgs,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or every day of the year if no day is given
+    Run { day: Option<u8>, part: Option<Part> },
+    /// Download the input of a day into the input cache
+    Fetch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Download the input again even if it is already cached
+        #[arg(long)]
+        force: bool,
+    },
+    /// Solve a part on the real input and submit its answer
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        part: Part,
+    },
+    /// Time parsing and both parts of a single day, or of every day of the year, on the real input
+    Bench {
+        day: Option<u8>,
+        /// How many times to run every day
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: u32,
+        /// Save the results as JSON
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Compare the results with those saved by an earlier run
+        #[arg(long)]
+        compare: Option<PathBuf>,
+        /// How much slower (in percent) a step may get before it is flagged
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Add a new day to the year, ready to be solved
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+#[derive(Parser)]
+struct DayCli {
+    #[command(flatten)]
+    input: InputArgs,
+    #[command(flatten)]
+    output: OutputArgs,
+    part: Option<Part>,
+}
+
+fn parse_cli<T: CommandFactory + FromArgMatches>(year: &Year) -> T {
+    let command = T::command().about(format!("Advent of Code {}", year.year));
+    T::from_arg_matches(&command.get_matches()).unwrap_or_else(|err| err.exit())
+}
+
+fn print_day(
+    year: &Year,
+    day: &Day,
+    input: &InputSource,
+    part: Option<Part>,
+    format: Format,
+) -> Result<()> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    for Answer {
+        part,
+        answer,
+        elapsed,
+    } in solve_day(year, day, input, &parts)?
+    {
+        let answer = match (answer, format) {
+            (Ok(answer), _) => answer,
+            (Err(err), Format::Text) => {
+                eprintln!("Part {}: {:#}", part, err);
+                continue;
+            }
+            (Err(err), _) => {
+                eprintln!("Day {:02} part {}: {:#}", day.day, part, err);
+                continue;
+            }
+        };
+
+        match format {
+            Format::Text => println!("Part {}: {}", part, answer),
+            Format::Json => println!(
+                "{}",
+                serde_json::to_string(&JsonAnswer {
+                    year: year.year,
+                    day: day.day,
+                    part: part.number(),
+                    answer: &answer,
+                    elapsed_ns: elapsed.as_nanos() as u64,
+                })?
+            ),
+            Format::Tsv => println!(
+                "{}\t{}\t{}\t{}\t{}",
+                year.year,
+                day.day,
+                part,
+                answer,
+                elapsed.as_nanos()
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+/// Submits the answer of a part unless the history already tells how that would go, and records
+/// the verdict.
+fn submit_answer(year: &Year, day: u8, part: Part) -> Result<()> {
+    let answer = solve_day(year, year.day(day)?, &InputSource::Real, &[part])?
+        .pop()
+        .ok_or_else(|| anyhow!("part {} was not solved", part))?
+        .answer?;
+    println!("Part {}: {}", part, answer);
+
+    let cache = Cache::from_env();
+    let mut history = History::load(cache.history_path())?;
+    for warning in history.check(year.year, day, part, &answer)? {
+        eprintln!("warning: {}", warning);
+    }
+
+    match Client::from_env()?.submit(year.year, day, part, &answer)? {
+        Response::Judged(verdict) => {
+            history.record(Attempt {
+                year: year.year,
+                day,
+                part,
+                answer,
+                verdict,
+            })?;
+            match verdict {
+                Verdict::Correct => println!("That's the right answer!"),
+                verdict => println!("That's not the right answer, it is {}", verdict),
+            }
+        }
+        Response::RateLimited(wait) => bail!(
+            "an answer was submitted too recently, wait {}s before trying again",
+            wait.as_secs()
+        ),
+        Response::WrongLevel => bail!("this part is either already solved or still locked"),
+    }
+
+    Ok(())
+}
+
+/// Entry point of the dispatcher binary.
+pub fn main(year: &Year) -> Result<()> {
+    let cli: Cli = parse_cli(year);
+    let input = cli.input.input;
+    let format = cli.output.format;
+    debug::set_enabled(cli.output.debug);
+
+    match cli.command {
+        Command::Run {
+            day: Some(day),
+            part,
+        } => print_day(year, year.day(day)?, &input, part, format),
+        Command::Run { day: None, part } => {
+            if matches!(input, InputSource::File(_) | InputSource::Stdin) {
+                bail!("a day has to be given to run on a file or stdin");
+            }
+
+            // A day that fails, even by panicking, is reported without stopping the others
+            let mut failed = 0;
+            for day in year.days {
+                if format == Format::Text {
+                    println!("Day {:02}", day.day);
+                }
+                let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                    print_day(year, day, &input, part, format)
+                }));
+                let err = match result {
+                    Ok(Ok(())) => continue,
+                    Ok(Err(err)) => format!("{:#}", err),
+                    Err(_) => "panicked".to_string(),
+                };
+                eprintln!("Day {:02}: {}", day.day, err);
+                failed += 1;
+            }
+
+            if failed > 0 {
+                bail!("{} of {} days failed", failed, year.days.len());
+            }
+            Ok(())
+        }
+        Command::Fetch { day, force } => {
+            let path =
+                Cache::from_env().fetch_input(&Client::from_env()?, year.year, day, force)?;
+            println!("Day {:02}: {}", day, path.display());
+            Ok(())
+        }
+        Command::Submit { day, part } => {
+            if input != InputSource::Real {
+                bail!("only answers on the real input can be submitted");
+            }
+            submit_answer(year, day, part)
+        }
+        Command::Bench {
+            day,
+            iterations,
+            save,
+            compare,
+            threshold,
+        } => {
+            if input != InputSource::Real {
+                bail!("only the real inputs can be benchmarked");
+            }
+
+            let days: Vec<&Day> = match day {
+                Some(day) => vec![year.day(day)?],
+                None => year.days.iter().collect(),
+            };
+            let previous = compare.as_deref().map(Report::load).transpose()?;
+            let report = bench::run(year, &days, iterations)?;
+            println!(
+                "{}",
+                report.to_markdown(previous.as_ref(), threshold / 100.0)
+            );
+
+            if let Some(path) = save {
+                report.save(&path)?;
+            }
+            Ok(())
+        }
+        Command::New { day } => {
+            for path in scaffold::new_day(year, day)? {
+                println!("{}", path.display());
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Entry point of the per-day binaries, e.g. `cargo run --bin aoc2024-day05 -- 2 --input sample`.
+pub fn main_for_day(year: &Year, day: u8) -> Result<()> {
+    let cli: DayCli = parse_cli(year);
+    debug::set_enabled(cli.output.debug);
+
+    print_day(
+        year,
+        year.day(day)?,
+        &cli.input.input,
+        cli.part,
+        cli.output.format,
+    )
+}
@@ -0,0 +1,82 @@
+use crate::solution::{solve_day, Answer, InputSource, Part, Year};
+
+/// Solves a day and checks its answers against the expected ones, skipping the parts whose
+/// expected answer is `None`.
+pub fn check_answers(year: &Year, day: u8, input: InputSource, expected: [Option<String>; 2]) {
+    let parts: Vec<Part> = Part::ALL
+        .into_iter()
+        .zip(&expected)
+        .filter(|(_, expected)| expected.is_some())
+        .map(|(part, _)| part)
+        .collect();
+
+    let answers = year
+        .day(day)
+        .and_then(|d| solve_day(year, d, &input, &parts))
+        .unwrap_or_else(|err| panic!("day {:02} failed: {:#}", day, err));
+
+    for Answer { part, answer, .. } in answers {
+        let answer = answer.unwrap_or_else(|err| panic!("part {} failed: {:#}", part, err));
+        let expected = match part {
+            Part::One => &expected[0],
+            Part::Two => &expected[1],
+        };
+        assert_eq!(
+            Some(&answer),
+            expected.as_ref(),
+            "wrong answer for part {}",
+            part
+        );
+    }
+}
+
+/// Turns an answer token from [`day_tests!`](crate::day_tests) into the expected output. Numbers
+/// are kept as written, so they are not limited to the range of any integer type, and string
+/// literals lose their quotes.
+pub fn expected(answer: &str) -> String {
+    answer
+        .strip_prefix('"')
+        .and_then(|a| a.strip_suffix('"'))
+        .unwrap_or(answer)
+        .to_string()
+}
+
+/// Declares the expected answers of a day on its sample and/or real input, each of which becomes a
+/// test. A part whose answer is not known (yet) is written as `_`, and attributes such as
+/// `#[ignore]` can be put before an input to apply to its test.
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     aoc_common::day_tests!(crate::YEAR, 5, sample: [35, 46], real: [261668924, _]);
+/// }
+/// ```
+#[macro_export]
+macro_rules! day_tests {
+    ($year:expr, $day:expr, $($(#[$attr:meta])* $input:ident: [$part1:tt, $part2:tt]),+ $(,)?) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $input() {
+                $crate::testing::check_answers(
+                    &$year,
+                    $day,
+                    $crate::day_tests!(@input $input),
+                    [$crate::day_tests!(@answer $part1), $crate::day_tests!(@answer $part2)],
+                );
+            }
+        )+
+    };
+    (@input sample) => {
+        $crate::solution::InputSource::Sample
+    };
+    (@input real) => {
+        $crate::solution::InputSource::Real
+    };
+    (@answer _) => {
+        None
+    };
+    (@answer $answer:tt) => {
+        Some($crate::testing::expected(stringify!($answer)))
+    };
+}
@@ -0,0 +1,122 @@
+use crate::grid::{Direction, Grid, Point};
+
+impl<T: Clone> Grid<T> {
+    /// Mirrors the grid along its main diagonal, so that rows become columns.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height(), self.width(), |p| {
+            self[Point::new(p.y, p.x)].clone()
+        })
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Grid<T> {
+        let height = self.height();
+        Grid::from_fn(height, self.width(), |p| {
+            self[Point::new(p.y, height - 1 - p.x)].clone()
+        })
+    }
+
+    /// Rotates the grid a quarter turn anticlockwise.
+    pub fn rotate_left(&self) -> Grid<T> {
+        let width = self.width();
+        Grid::from_fn(self.height(), width, |p| {
+            self[Point::new(width - 1 - p.y, p.x)].clone()
+        })
+    }
+
+    pub fn rotate_180(&self) -> Grid<T> {
+        let (width, height) = (self.width(), self.height());
+        Grid::from_fn(width, height, |p| {
+            self[Point::new(width - 1 - p.x, height - 1 - p.y)].clone()
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let width = self.width();
+        Grid::from_fn(width, self.height(), |p| {
+            self[Point::new(width - 1 - p.x, p.y)].clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let height = self.height();
+        Grid::from_fn(self.width(), height, |p| {
+            self[Point::new(p.x, height - 1 - p.y)].clone()
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// Slides every cell for which `moves` holds as far as it can in `direction`, stopping at the
+    /// edge of the grid or at the first cell that is neither moving nor `empty`. Think of rolling
+    /// rocks when the grid is tilted.
+    pub fn slide(
+        &mut self,
+        direction: Direction,
+        moves: impl Fn(&T) -> bool,
+        empty: impl Fn(&T) -> bool,
+    ) {
+        let (width, height) = (self.width(), self.height());
+        // Each lane runs from the edge the cells slide towards
+        let lanes: Vec<Vec<Point>> = match direction {
+            Direction::Up => (0..width)
+                .map(|x| (0..height).map(|y| Point::new(x, y)).collect())
+                .collect(),
+            Direction::Down => (0..width)
+                .map(|x| (0..height).rev().map(|y| Point::new(x, y)).collect())
+                .collect(),
+            Direction::Left => (0..height)
+                .map(|y| (0..width).map(|x| Point::new(x, y)).collect())
+                .collect(),
+            Direction::Right => (0..height)
+                .map(|y| (0..width).rev().map(|x| Point::new(x, y)).collect())
+                .collect(),
+        };
+
+        for lane in lanes {
+            let mut free = 0;
+            for i in 0..lane.len() {
+                let cell = &self[lane[i]];
+                if moves(cell) {
+                    self.swap(lane[free], lane[i]);
+                    free += 1;
+                } else if !empty(cell) {
+                    free = i + 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Grid<char> {
+        Grid::from_str(s, |c| c).unwrap()
+    }
+
+    #[test]
+    fn transforms() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.transpose(), grid("ad\nbe\ncf"));
+        assert_eq!(g.rotate_right(), grid("da\neb\nfc"));
+        assert_eq!(g.rotate_left(), grid("cf\nbe\nad"));
+        assert_eq!(g.rotate_180(), grid("fed\ncba"));
+        assert_eq!(g.flip_horizontal(), grid("cba\nfed"));
+        assert_eq!(g.flip_vertical(), grid("def\nabc"));
+        assert_eq!(g.rotate_right().rotate_left(), g);
+    }
+
+    #[test]
+    fn slides_until_blocked() {
+        let mut g = grid("O.#.O\n..O..\nO#..O");
+        g.slide(Direction::Right, |c| *c == 'O', |c| *c == '.');
+        assert_eq!(g, grid(".O#.O\n....O\nO#..O"));
+
+        g.slide(Direction::Up, |c| *c == 'O', |c| *c == '.');
+        assert_eq!(g, grid("OO#.O\n....O\n.#..O"));
+    }
+}
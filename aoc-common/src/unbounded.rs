@@ -0,0 +1,173 @@
+use std::{
+    collections::HashMap,
+    ops::{Index, IndexMut},
+};
+
+use anyhow::{bail, Result};
+
+use crate::grid::{Grid, Point};
+
+/// A grid that only stores the cells that have been set, at any signed position. Its bounds grow
+/// to fit the cells that are inserted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point<i64>, T>,
+    bounds: Option<(Point<i64>, Point<i64>)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid::default()
+    }
+
+    /// Parses a grid with one row per line and the top left cell at `(0, 0)`, only keeping the
+    /// cells for which `parse` gives a value.
+    pub fn from_str(s: &str, mut parse: impl FnMut(char) -> Option<T>) -> SparseGrid<T> {
+        let mut grid = SparseGrid::new();
+        for (y, line) in s.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if let Some(value) = parse(c) {
+                    grid.insert(Point::new(x as i64, y as i64), value);
+                }
+            }
+        }
+
+        grid
+    }
+
+    pub fn get(&self, point: &Point<i64>) -> Option<&T> {
+        self.cells.get(point)
+    }
+
+    pub fn get_mut(&mut self, point: &Point<i64>) -> Option<&mut T> {
+        self.cells.get_mut(point)
+    }
+
+    pub fn contains(&self, point: &Point<i64>) -> bool {
+        self.cells.contains_key(point)
+    }
+
+    /// Sets a cell, returning its old value if it had one.
+    pub fn insert(&mut self, point: Point<i64>, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => (point, point),
+            Some((min, max)) => (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            ),
+        });
+        self.cells.insert(point, value)
+    }
+
+    /// Clears a cell. The bounds do not shrink, as that would mean going over every other cell.
+    pub fn remove(&mut self, point: &Point<i64>) -> Option<T> {
+        self.cells.remove(point)
+    }
+
+    /// The top left and bottom right corners of every cell that has been set so far.
+    pub fn bounds(&self) -> Option<(Point<i64>, Point<i64>)> {
+        self.bounds
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Every set cell along with its point, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Point<i64>, &T)> {
+        self.cells.iter()
+    }
+}
+
+/// A finite [`Grid`] repeated endlessly in every direction, so that any signed point maps onto
+/// one of its cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TiledGrid<T> {
+    tile: Grid<T>,
+}
+
+impl<T> TiledGrid<T> {
+    /// Repeats `tile`, which needs at least one cell for points to land on.
+    pub fn new(tile: Grid<T>) -> Result<TiledGrid<T>> {
+        if tile.width() == 0 || tile.height() == 0 {
+            bail!("cannot tile an empty grid");
+        }
+
+        Ok(TiledGrid { tile })
+    }
+
+    /// The grid that gets repeated.
+    pub fn tile(&self) -> &Grid<T> {
+        &self.tile
+    }
+
+    /// The cell of the tile that `point` lands on.
+    pub fn wrap(&self, point: Point<i64>) -> Point {
+        let wrapped = point.rem_euclid(self.tile.width() as i64, self.tile.height() as i64);
+        // The tile is never empty, so this is always a cell of it and in particular not negative
+        Point::new(wrapped.x as usize, wrapped.y as usize)
+    }
+
+    /// Which copy of the tile `point` is in, with the original one at `(0, 0)`.
+    pub fn tile_of(&self, point: Point<i64>) -> Point<i64> {
+        Point::new(
+            point.x.div_euclid(self.tile.width() as i64),
+            point.y.div_euclid(self.tile.height() as i64),
+        )
+    }
+}
+
+impl<T> Index<Point<i64>> for TiledGrid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<i64>) -> &T {
+        &self.tile[self.wrap(point)]
+    }
+}
+
+impl<T> IndexMut<Point<i64>> for TiledGrid<T> {
+    fn index_mut(&mut self, point: Point<i64>) -> &mut T {
+        let point = self.wrap(point);
+        &mut self.tile[point]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grows_sparse_bounds() {
+        let mut grid = SparseGrid::from_str("#..\n..#", |c| (c == '#').then_some(()));
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.bounds(), Some((Point::new(0, 0), Point::new(2, 1))));
+
+        grid.insert(Point::new(-3, 5), ());
+        assert!(grid.contains(&Point::new(-3, 5)));
+        assert_eq!(grid.bounds(), Some((Point::new(-3, 0), Point::new(2, 5))));
+    }
+
+    #[test]
+    fn wraps_tiles() {
+        let grid = TiledGrid::new(Grid::from_str("ab\ncd", |c| c).unwrap()).unwrap();
+        assert_eq!(grid[Point::new(0, 0)], 'a');
+        assert_eq!(grid[Point::new(-1, 0)], 'b');
+        assert_eq!(grid[Point::new(5, -3)], 'd');
+        assert_eq!(grid.tile_of(Point::new(-1, 4)), Point::new(-1, 2));
+
+        assert!(TiledGrid::new(Grid::new(0, 3, 'a')).is_err());
+    }
+}